@@ -1,19 +1,9 @@
 use adventofcode2022 as aoc;
 
 pub fn main() {
-	let lines = aoc::input::read_lines("day01");
-
-	let mut elfs = Vec::new();
-	let mut calories = 0;
-
-	for line in lines {
-		if line == "" {
-			elfs.push(calories);
-			calories = 0;
-		} else {
-			calories += line.parse::<u32>().unwrap();
-		}
-	}
+	let mut elfs = aoc::input::parse_blocks::<Vec<u32>>(aoc::input::stdin_or("day01"))
+		.map(|calories| calories.iter().sum())
+		.collect::<Vec<u32>>();
 
 	elfs.sort_by_key(|&x| std::cmp::Reverse(x));
 
@@ -2,7 +2,7 @@ use adventofcode2022 as aoc;
 use std::collections::BTreeSet;
 
 fn main() {
-	let lines = aoc::input::read_lines("day03").collect();
+	let lines = aoc::input::read_lines(aoc::input::stdin_or("day03")).collect();
 	part1(&lines);
 	part2(&lines);
 }
@@ -1,14 +1,16 @@
 use adventofcode2022 as aoc;
 
 fn main() {
-	let lines = aoc::input::read_tokens_split_chars::<u32>("day04", &['-', ',']);
+	let lines = aoc::input::stream_tokens_split_chars::<(u32, u32, u32, u32), _>(
+		aoc::input::stdin_or("day04"), &['-', ','])
+		.unwrapped();
 
 	let mut count_part1 = 0;
 	let mut count_part2 = 0;
 
-	for line in lines {
-		let days1 = line[0]..=line[1];
-		let days2 = line[2]..=line[3];
+	for (start1, end1, start2, end2) in lines {
+		let days1 = start1..=end1;
+		let days2 = start2..=end2;
 
 		if (days2.contains(&days1.start()) && days2.contains(&days1.end()))
 				|| (days1.contains(&days2.start()) && days1.contains(&days2.end())) {
@@ -1,4 +1,5 @@
 use adventofcode2022 as aoc;
+use aoc::input::InputSource;
 
 #[derive(Debug)]
 struct Move {
@@ -8,7 +9,7 @@ struct Move {
 }
 
 fn main() {
-	let (stacks, moves) = parse_input("day05");
+	let (stacks, moves) = parse_input(aoc::input::stdin_or("day05"));
 	println!("Part 1: top elements '{}'", solve(stacks.clone(), &moves, true));
 	println!("Part 2: top elements '{}'", solve(stacks, &moves, false));
 }
@@ -29,13 +30,14 @@ fn top_elems(stacks: &Vec<Vec<char>>) -> String {
 	stacks.iter().map(|stack| stack.last().unwrap()).collect()
 }
 
-fn parse_input(day_xx: &str) -> (Vec<Vec<char>>, Vec<Move>) {
-	let lines: Vec<String> = aoc::input::read_lines(day_xx).collect();
-	let split_idx = lines.iter().position(|l| l.starts_with(" 1")).unwrap();
-	let stacks_count = lines[split_idx].split_whitespace().count();
+fn parse_input(day_xx: impl InputSource) -> (Vec<Vec<char>>, Vec<Move>) {
+	let mut blocks = aoc::input::read_blocks(day_xx);
+	let (stacks, moves) = (blocks.next().unwrap(), blocks.next().unwrap());
+	let (stacks_nums, stacks) = stacks.split_last().unwrap();
+	let stacks_count = stacks_nums.split_whitespace().count();
 	(
-		parse_stacks(&lines[..split_idx], stacks_count),
-		parse_moves(&lines[split_idx + 2..])
+		parse_stacks(stacks, stacks_count),
+		parse_moves(&moves)
 	)
 }
 
@@ -68,16 +70,29 @@ fn parse_moves(lines: &[String]) -> Vec<Move> {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use aoc::input::Text;
+
+	const EXAMPLE: &str = concat!(
+		"    [D]    \n",
+		"[N] [C]    \n",
+		"[Z] [M] [P]\n",
+		" 1   2   3 \n",
+		"\n",
+		"move 1 from 2 to 1\n",
+		"move 3 from 1 to 3\n",
+		"move 2 from 2 to 1\n",
+		"move 1 from 1 to 2\n",
+	);
 
 	#[test]
 	fn test_part1() {
-		let (stacks, moves) = parse_input("day05-test");
+		let (stacks, moves) = parse_input(Text(EXAMPLE));
 		assert_eq!(solve(stacks, &moves, true), "CMZ");
 	}
 
 	#[test]
 	fn test_part2() {
-		let (stacks, moves) = parse_input("day05-test");
+		let (stacks, moves) = parse_input(Text(EXAMPLE));
 		assert_eq!(solve(stacks, &moves, false), "MCD");
 	}
 }
\ No newline at end of file
@@ -1,8 +1,9 @@
-use std::{fs, collections::BTreeSet};
+use adventofcode2022 as aoc;
+use std::collections::BTreeSet;
 
 fn main() {
-	let signal = fs::read_to_string("input/day06.txt").unwrap();
-	let signal = signal.trim().as_bytes();
+	let input = aoc::input::read_bytes(aoc::input::stdin_or("day06"));
+	let signal = input.trim_ascii();
 	println!("Part 1: start-of-message at pos {}", find_unique_serie(&signal, 4).unwrap());
 	println!("Part 2: start-of-packet at pos {}", find_unique_serie(&signal, 14).unwrap());
 }
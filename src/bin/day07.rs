@@ -1,4 +1,5 @@
 use adventofcode2022 as aoc;
+use aoc::input::InputSource;
 use std::collections::HashMap;
 use std::cell::RefCell;
 use std::borrow::Borrow;
@@ -12,7 +13,7 @@ struct Dir {
 }
 
 fn main() {
-	let root = parse_input("day07");
+	let root = parse_input(aoc::input::stdin_or("day07"));
 	println!("Part 1: size {}", sum_lte_100k(&root));
 	println!("Part 2: size {}", smallest_size_to_free(&root, 40_000_000).unwrap())
 }
@@ -38,7 +39,7 @@ fn smallest_size_gte(root: &Dir, size: u64) -> Option<u64> {
 		.min()
 }
 
-fn parse_input(day_xx: &str) -> Dir {
+fn parse_input(day_xx: impl InputSource) -> Dir {
 	let lines = aoc::input::read_tokens_split_chars::<String>(day_xx, &[' ']);
 
 	let mut root = Dir::new();
@@ -98,10 +99,37 @@ impl Dir {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use aoc::input::Text;
+
+	const EXAMPLE: &str = "\
+$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k
+";
 
 	#[test]
 	fn test_parse_tree() {
-		let parsed = parse_input("day07-test");
+		let parsed = parse_input(Text(EXAMPLE));
 
 		let mut root = Dir::new();
 		root.insert_dir("a");
@@ -129,13 +157,13 @@ mod tests {
 
 	#[test]
 	fn test_part1() {
-		let mut root = parse_input("day07-test");
+		let mut root = parse_input(Text(EXAMPLE));
 		assert_eq!(sum_lte_100k(&mut root), 95437);
 	}
 
 	#[test]
 	fn test_part2() {
-		let mut root = parse_input("day07-test");
+		let mut root = parse_input(Text(EXAMPLE));
 		assert_eq!(smallest_size_to_free(&mut root, 40_000_000), Some(24933642));
 	}
 }
\ No newline at end of file
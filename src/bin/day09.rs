@@ -1,11 +1,8 @@
 use adventofcode2022 as aoc;
+use aoc::space_2D::Direction;
 use std::collections::HashSet;
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-struct Point {
-	x: i32,
-	y: i32
-}
+type Point = aoc::space_2D::Point<isize>;
 
 fn main() {
 	let moves = parse_input();
@@ -13,28 +10,19 @@ fn main() {
 	println!("Part 2: tail visited {} positions", rope_move(&moves, 10));
 }
 
-fn rope_move(moves: &Vec<(String, i32)>, rope_size: usize) -> usize {
-	let mut rope = vec![Point{x: 0, y: 0}; rope_size];
+fn rope_move(moves: &[(Direction, u32)], rope_size: usize) -> usize {
+	let mut rope = vec![Point::default(); rope_size];
 	let mut tail_visited = HashSet::new();
-	tail_visited.insert(Point{x: 0, y: 0});
+	tail_visited.insert(Point::default());
 
 	for (dir, num) in moves {
 		for _ in 0..*num {
-			let head = &mut rope[0];
-			match dir.as_str() {
-				"L" => head.y -= 1,
-				"R" => head.y += 1,
-				"U" => head.x += 1,
-				"D" => head.x -= 1,
-				_ => panic!("Wrong direction {}", dir),
-			};
+			rope[0] += dir.to_point();
 
 			for i in 0..rope_size - 1{
-				let dx = rope[i].x - rope[i + 1].x;
-				let dy = rope[i].y - rope[i + 1].y;
-				if dx.abs() > 1 || dy.abs() > 1 {
-					rope[i + 1].x += dx.signum();
-					rope[i + 1].y += dy.signum();
+				let diff = rope[i] - rope[i + 1];
+				if diff.x.abs() > 1 || diff.y.abs() > 1 {
+					rope[i + 1] += diff.signum().into();
 				}
 			}
 
@@ -45,9 +33,8 @@ fn rope_move(moves: &Vec<(String, i32)>, rope_size: usize) -> usize {
 	tail_visited.len()
 }
 
-fn parse_input() -> Vec<(String, i32)> {
-	aoc::input::read_lines("day09").map(|l| {
-		let tokens: Vec<&str> = l.split(" ").collect();
-		(tokens[0].to_string(), tokens[1].parse::<i32>().unwrap())
-	}).collect()
-}
\ No newline at end of file
+fn parse_input() -> Vec<(Direction, u32)> {
+	aoc::input::stream_tokens_split_str(aoc::input::stdin_or("day09"), " ")
+		.unwrapped()
+		.collect()
+}
@@ -1,5 +1,6 @@
 use adventofcode2022 as aoc;
 use aoc::input::ParseAoCInputError;
+use aoc::space_2D::{ArrayGrid, Grid, Point};
 use std::str::FromStr;
 
 enum Instr {
@@ -8,13 +9,13 @@ enum Instr {
 }
 
 fn main() {
-	let program = aoc::input::parse_lines::<Instr>("day10");
+	let program = aoc::input::parse_lines::<Instr>(aoc::input::stdin_or("day10"));
 
 	let mut x: i32 = 1;
 	let mut cycles = 0;
 	let mut signal_strength = 0;
 	let cycles_check_strength = [20, 60, 100, 140, 180, 220];
-	let mut screen = [[' '; 40]; 6];
+	let mut screen = ArrayGrid::new(6, 40, false);
 
 	for instr in program {
 		for _n in 0..instr.cycles() {
@@ -22,7 +23,7 @@ fn main() {
 			let col = cycles % 40;
 			let sprite = x - 1..=x + 1;
 			if sprite.contains(&col) {
-				screen[cycles as usize / 40][col as usize] = '#';
+				screen[Point::from((cycles as usize / 40, col as usize))] = true;
 			}
 
 			cycles += 1;
@@ -41,9 +42,7 @@ fn main() {
 
 	println!("Part 1: signal strength {}", signal_strength);
 	println!("Part 2:");
-	for row in screen {
-		println!("{}", row.iter().collect::<String>());
-	}
+	print!("{}", Grid::<usize>::render(&screen, |&lit| match lit { true => '#', false => ' ' }));
 }
 
 impl Instr {
@@ -1,4 +1,5 @@
 use adventofcode2022 as aoc;
+use aoc::input::{InputSource, FromLines, ParseAoCInputError};
 use std::collections::VecDeque;
 use std::cmp;
 
@@ -20,7 +21,7 @@ enum Op {
 }
 
 fn main() {
-	let monkeys = parse_input("day11");
+	let monkeys = parse_input(aoc::input::stdin_or("day11"));
 	println!("Part 1: monkey business {}", solve(monkeys.clone(), 20, 3));
 	println!("Part 2: monkey business {}", solve(monkeys, 10000, 1));
 }
@@ -60,27 +61,36 @@ impl Op {
 	}
 }
 
-fn parse_input(day_xx: &str) -> Vec<Monkey> {
-	let input = aoc::input::read_lines(day_xx).collect::<Vec<_>>();
-	input.chunks(7).map(parse_monkey_data).collect()
+fn parse_input(day_xx: impl InputSource) -> Vec<Monkey> {
+	aoc::input::parse_blocks(day_xx).collect()
 }
 
-fn parse_monkey_data(monkey_data: &[String]) -> Monkey {
-	let items = monkey_data[1][18..].split(", ").map(|v| v.parse().unwrap()).collect();
-	let op = &monkey_data[2][23..24];
-	let val = &monkey_data[2][25..];
-	let test_div_by = monkey_data[3][21..].parse().unwrap();
-	let next_true = monkey_data[4][29..].parse().unwrap();
-	let next_false = monkey_data[5][30..].parse().unwrap();
-
-	let op = match (op, val) {
-		("*", "old") => Op::Sq,
-		("*", num) => Op::Mul(num.parse().unwrap()),
-		("+", num) => Op::Sum(num.parse().unwrap()),
-		_ => panic!(),
-	};
-
-	Monkey {items, op, test_div_by, next_true, next_false, inspections: 0}
+impl FromLines for Monkey {
+	type Err = ParseAoCInputError<Self>;
+
+	fn from_lines(monkey_data: &[String]) -> Result<Self, Self::Err> {
+		// the value after the prefix in the line i, without the indentation
+		let field = |i: usize, prefix: &str| {
+			let line = monkey_data.get(i)
+				.ok_or_else(|| ParseAoCInputError::new_custom(&format!("missing line {} of monkey", i + 1)))?;
+			line.trim_start().strip_prefix(prefix).ok_or_else(|| ParseAoCInputError::new(line))
+		};
+
+		let items = field(1, "Starting items: ")?.split(", ").map(|v| v.parse()).collect::<Result<_, _>>()?;
+		let operation = field(2, "Operation: new = old ")?;
+		let test_div_by = field(3, "Test: divisible by ")?.parse()?;
+		let next_true = field(4, "If true: throw to monkey ")?.parse()?;
+		let next_false = field(5, "If false: throw to monkey ")?.parse()?;
+
+		let op = match operation.split_once(' ') {
+			Some(("*", "old")) => Op::Sq,
+			Some(("*", num)) => Op::Mul(num.parse()?),
+			Some(("+", num)) => Op::Sum(num.parse()?),
+			_ => return Err(ParseAoCInputError::new(operation)),
+		};
+
+		Ok(Monkey {items, op, test_div_by, next_true, next_false, inspections: 0})
+	}
 }
 
 #[cfg(test)]
@@ -93,6 +103,18 @@ mod tests {
 		assert_eq!(solve(monkeys, 20, 3), 10605);
 	}
 
+	#[test]
+	fn test_parse_errors() {
+		let lines = |text: &str| text.lines().map(String::from).collect::<Vec<_>>();
+		assert!(Monkey::from_lines(&lines("Monkey 0:\n  Starting items: 79, 98")).is_err());
+		assert!(Monkey::from_lines(&lines("Monkey 0:\n  Starting: 79\n")).is_err());
+
+		let monkey = "Monkey 0:\n  Starting items: 79\n  Operation: new = old - 3\n  Test: divisible by 2\n    \
+			If true: throw to monkey 1\n    If false: throw to monkey 2";
+		assert!(Monkey::from_lines(&lines(monkey)).is_err());
+		assert!(Monkey::from_lines(&lines(&monkey.replace('-', "*"))).is_ok());
+	}
+
 	#[test]
 	fn test_part2() {
 		let monkeys = parse_input("day11-test");
@@ -53,8 +53,8 @@ fn packets_cmp(lpacket: &[Elem], rpacket: &[Elem]) -> Ordering {
 }
 
 fn parse_input() -> Vec<Packet> {
-	aoc::input::read_lines("day13")
-		.filter(|l| !l.is_empty())
+	aoc::input::read_blocks(aoc::input::stdin_or("day13"))
+		.flatten()
 		.map(|l| parse_packet(&l).0)
 		.collect()
 }
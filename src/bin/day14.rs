@@ -1,7 +1,9 @@
 use adventofcode2022 as aoc;
-use aoc::space_2D::{Point, VecGrid, Grid};
+use aoc::space_2D::{Point, ArrayGrid, Grid as GridTrait};
 use Material::*;
 
+type Grid = ArrayGrid<Material>;
+
 const SAND_START_POS: Point = Point {y: 0, x: 500};
 
 #[derive(Debug, Clone, PartialEq, Copy)]
@@ -17,7 +19,7 @@ fn main() {
 	println!("Part 2: {} units of sand", part2(grid));
 }
 
-fn part1(mut grid: VecGrid<Material>) -> u32 {
+fn part1(mut grid: Grid) -> u32 {
 	let max_rock_y = calc_max_rock_y(&grid).unwrap();
 	let mut sand_count = 0;
 
@@ -34,7 +36,7 @@ fn part1(mut grid: VecGrid<Material>) -> u32 {
 	}
 }
 
-fn part2(mut grid: VecGrid<Material>) -> u32 {
+fn part2(mut grid: Grid) -> u32 {
 	let floor_y = calc_max_rock_y(&grid).unwrap() + 2;
 	let mut sand_count = 0;
 
@@ -50,7 +52,7 @@ fn part2(mut grid: VecGrid<Material>) -> u32 {
 	sand_count
 }
 
-fn next_pos(grid: &VecGrid<Material>, pos: Point, max_y: Option<usize>) -> Option<Point> {
+fn next_pos(grid: &Grid, pos: Point, max_y: Option<usize>) -> Option<Point> {
 	if max_y.is_some() && pos.y + 1 == max_y.unwrap() {
 		return None;
 	}
@@ -64,12 +66,12 @@ fn next_pos(grid: &VecGrid<Material>, pos: Point, max_y: Option<usize>) -> Optio
 	})
 }
 
-fn calc_max_rock_y(grid: &VecGrid<Material>) -> Option<usize> {
+fn calc_max_rock_y(grid: &Grid) -> Option<usize> {
 	grid.iter_grid().filter_map(|(p, m)| if *m == Rock {Some(p.y)} else {None}).max()
 }
 
-fn parse_input() -> VecGrid<Material> {
-	let input = aoc::input::read_tokens_split_str::<String>("day14", " -> ")
+fn parse_input() -> Grid {
+	let input = aoc::input::read_tokens_split_str::<String>(aoc::input::stdin_or("day14"), " -> ")
 		.map(|coords| coords.into_iter().map(|coord| {
 				let coord = coord.split(",").collect::<Vec<_>>();
 				let x = coord[0].parse().unwrap();
@@ -78,19 +80,15 @@ fn parse_input() -> VecGrid<Material> {
 			}).collect::<Vec<Point>>()
 		).collect::<Vec<Vec<Point>>>();
 	
+	// the sand of part 2 spreads at most floor_y cells to each side of the start
 	let max_y = input.iter().flat_map(|l| l.iter().map(|p| p.y)).max().unwrap();
 	let max_x = input.iter().flat_map(|l| l.iter().map(|p| p.x)).max().unwrap();
-	let mut grid = vec![vec![Air; max_x * 2]; max_y + 2];
+	let mut grid = Grid::new(max_y + 2, max_x.max(SAND_START_POS.x) + max_y + 3, Air);
 
 	// create the lines of rock joining all the line's points
-	for mut line in input {
-		let mut point = line.pop().unwrap();
-		grid[point] = Rock;
-
-		while let Some(next) = line.pop() {
-			let sum: Point<isize> = (next - point).signum().into();
-			while point != next {
-				point = point.add_signed(sum);
+	for line in input {
+		for (start, end) in line.iter().zip(line.iter().skip(1)) {
+			for point in start.line_to(*end).unwrap() {
 				grid[point] = Rock;
 			}
 		}
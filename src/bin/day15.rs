@@ -1,15 +1,13 @@
 use adventofcode2022 as aoc;
-use aoc::input::ParseAoCInputError;
+use aoc::input::{InputSource, ParseAoCInputError, Pattern};
+use aoc::space_2D::Rect;
 use std::ops::RangeInclusive;
 use std::str::FromStr;
 use std::collections::BTreeSet;
-use regex::Regex;
-
-#[macro_use]
-extern crate lazy_static;
 
 const PART1_LINE: isize = 2_000_000;
 const PART2_SEARCH_SIZE: isize = 4_000_000;
+const SENSOR_PATTERN: Pattern = Pattern::new("Sensor at x={}, y={}: closest beacon is at x={}, y={}");
 
 type Point = aoc::space_2D::Point<isize>;
 
@@ -20,7 +18,7 @@ struct Sensor {
 }
 
 fn main() {
-	let sensors = parse_input("day15");
+	let sensors = parse_input(aoc::input::stdin_or("day15"));
 	println!("Part 1: {} points without beacon", part1(&sensors, PART1_LINE));
 	println!("Part 2: frequency {}", part2(&sensors, PART2_SEARCH_SIZE));
 }
@@ -41,10 +39,12 @@ fn part1(sensors: &[Sensor], line_num: isize) -> usize {
 }
 
 fn part2(sensors: &[Sensor], search_size: isize) -> u64 {
-	for line in 0..=search_size {
+	let search_area = Rect::new(Point::from((0, 0)), Point::from((search_size, search_size)));
+
+	for line in search_area.min.y..=search_area.max.y {
 		let scanned_ranges = get_line_scanned_ranges(sensors, line);
 
-		if let Some(pos) = find_line_unscanned_point(&scanned_ranges, search_size) {
+		if let Some(pos) = find_line_unscanned_point(&scanned_ranges, search_area) {
 			return pos as u64 * 4000000 + line as u64
 		}
 	}
@@ -56,8 +56,7 @@ fn get_line_scanned_ranges(sensors: &[Sensor], line_num: isize) -> Vec<(isize, i
 	let mut ranges = Vec::new();
 
 	for sensor in sensors {
-		let beacon_rel = sensor.pos - sensor.beacon;
-		let max_dist = beacon_rel.x.abs() + beacon_rel.y.abs();
+		let max_dist = sensor.pos.manhattan_dist(sensor.beacon) as isize;
 		let x_diff = max_dist - (line_num - sensor.pos.y).abs();
 		if x_diff >= 0 {
 			ranges.push((sensor.pos.x - x_diff, sensor.pos.x + x_diff));
@@ -91,10 +90,10 @@ fn can_merge(range1: &RangeInclusive<isize>, range2: &RangeInclusive<isize>) ->
 	|| range1.end() + 1 == *range2.start() || range2.start() + 1 == *range1.end()
 }
 
-fn find_line_unscanned_point(scanned_ranges: &Vec<(isize, isize)>, line_size: isize)
+fn find_line_unscanned_point(scanned_ranges: &Vec<(isize, isize)>, search_area: Rect<isize>)
 	-> Option<isize>
 {
-	let mut pos = 0;
+	let mut pos = search_area.min.x;
 	for range in scanned_ranges {
 		if pos >= range.0 && pos <= range.1 {
 			pos = range.1 + 1;
@@ -102,12 +101,12 @@ fn find_line_unscanned_point(scanned_ranges: &Vec<(isize, isize)>, line_size: is
 	}
 
 	match pos {
-		pos if pos <= line_size => Some(pos),
+		pos if pos <= search_area.max.x => Some(pos),
 		_ => None
 	}
 }
 
-fn parse_input(day_xx: &str) -> Vec<Sensor> {
+fn parse_input(day_xx: impl InputSource) -> Vec<Sensor> {
 	aoc::input::parse_lines(day_xx).collect()
 }
 
@@ -115,17 +114,11 @@ impl FromStr for Sensor {
 	type Err = ParseAoCInputError<Self>;
 
 	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		lazy_static! {
-			static ref RE: Regex = Regex::new(r"^Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)$").unwrap();
-		}
-
-		match RE.captures_iter(s).next() {
-			Some(cap) => Ok(Sensor {
-				pos: Point::from((cap[2].parse()?, cap[1].parse()?)),
-				beacon: Point::from((cap[4].parse()?, cap[3].parse()?))
-			}),
-			None => Err(ParseAoCInputError::new(s))
-		}
+		let (x, y, beacon_x, beacon_y) = SENSOR_PATTERN.parse(s)?;
+		Ok(Sensor {
+			pos: Point::from((y, x)),
+			beacon: Point::from((beacon_y, beacon_x))
+		})
 	}
 }
 
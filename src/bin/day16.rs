@@ -1,7 +1,11 @@
 use adventofcode2022 as aoc;
+use aoc::input::{InputSource, Pattern};
+use aoc::search;
 use itertools::Itertools;
 use std::collections::{HashSet, HashMap};
 
+const VALVE_PATTERN: Pattern = Pattern::new("Valve {} has flow rate={}; {}");
+
 struct Valve {
 	flow: i32,
 	tunnels: Vec<usize>,
@@ -23,7 +27,7 @@ struct StatePart2 {
 }
 
 fn main() {
-	let (mut valves, id_start) = parse_input("day16");
+	let (mut valves, id_start) = parse_input(aoc::input::stdin_or("day16"));
 	let ids_with_flow = precompute(&mut valves, id_start);
 
 	println!("Part 1: max released pressure {}", part1(&valves, id_start, &ids_with_flow));
@@ -45,22 +49,8 @@ fn precompute(valves: &mut [Valve], id_start: usize) -> HashSet<usize> {
 }
 
 fn calc_distances_from(valves: &[Valve], id: usize) -> Vec<usize> {
-	let mut distances = vec![usize::MAX; valves.len()];
-	let mut queue = vec![id];
-	distances[id] = 0;
-
-	while let Some(id) = queue.pop() {
-		let dist = distances[id];
-
-		for &neigh in &valves[id].tunnels {
-			if distances[neigh] > dist + 1 {
-				distances[neigh] = dist + 1;
-				queue.push(neigh);
-			}
-		}
-	}
-
-	distances
+	let found = search::bfs([id], |&id| valves[id].tunnels.iter().copied(), |_| false);
+	(0..valves.len()).map(|id| found.cost(&id).unwrap_or(usize::MAX)).collect()
 }
 
 fn part1(valves: &[Valve], id_start: usize, ids_with_flow: &HashSet<usize>) -> i32 {
@@ -243,7 +233,7 @@ fn optimistic_potential_pressure_part2(valves: &[Valve], unvisited: &HashSet<usi
 		.sum()
 }
 
-fn parse_input(day_xx: &str) -> (Vec<Valve>, usize) {
+fn parse_input(day_xx: impl InputSource) -> (Vec<Valve>, usize) {
 	let mut ids_map = HashMap::new();
 	let mut id_start = 0;
 
@@ -251,10 +241,10 @@ fn parse_input(day_xx: &str) -> (Vec<Valve>, usize) {
 		.into_iter()
 		.enumerate()
 		.map(|(id, line)| {
-			let line_parts = line.split("; ").collect::<Vec<_>>();
-			let valve = line_parts[0][6.. 8].to_string();
-			let flow = line_parts[0][23..].parse().unwrap();
-			let tunnels = line_parts[1][22..].split(", ").map(|s| s.trim().to_string()).collect::<Vec<_>>();
+			let (valve, flow, tunnels): (String, i32, String) = VALVE_PATTERN.parse::<Valve, _>(&line).unwrap();
+			// tunnels is like "tunnels lead to valves DD, II" or "tunnel leads to valve GG"
+			let tunnels = tunnels.splitn(5, ' ').last().unwrap()
+				.split(", ").map(|s| s.to_string()).collect::<Vec<_>>();
 
 			if valve == "AA" {
 				id_start = id;
@@ -279,17 +269,31 @@ fn parse_input(day_xx: &str) -> (Vec<Valve>, usize) {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use aoc::input::Text;
+
+	const EXAMPLE: &str = "\
+Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II
+";
 
 	#[test]
 	fn test_part1() {
-		let (mut valves, id_start) = parse_input("day16-test");
+		let (mut valves, id_start) = parse_input(Text(EXAMPLE));
 		let ids_with_flow = precompute(&mut valves, id_start);
 		assert_eq!(part1(&valves, id_start, &ids_with_flow), 1651);
 	}
 
 	#[test]
 	fn test_part2() {
-		let (mut valves, id_start) = parse_input("day16-test");
+		let (mut valves, id_start) = parse_input(Text(EXAMPLE));
 		let ids_with_flow = precompute(&mut valves, id_start);
 		assert_eq!(part2(&valves, id_start, &ids_with_flow), 1707);
 	}
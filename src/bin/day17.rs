@@ -1,19 +1,18 @@
 use adventofcode2022 as aoc;
-use aoc::space_2D::{Point, Grid, VecGrid};
+use aoc::space_2D::{Point, BitGrid, Direction};
 use std::collections::HashMap;
 
 const TOWER_WIDTH: usize = 7;
-const RIGHT: Point<isize> = Point {y: 0, x: 1};
-const LEFT: Point<isize> = Point {y: 0, x: -1};
-const DOWN: Point<isize> = Point {y: -1, x: 0};
+const DOWN: Point<isize> = Point {y: -1, x: 0};  // the tower grows with y
 
+/// the rows of the shape as bits, from bottom to top
 #[derive(PartialEq)]
-struct Shape(Vec<Vec<bool>>);
+struct Shape(BitGrid);
 
-type State = (usize, usize, Vec<bool>);
+type State = (usize, usize, Vec<u64>);
 
 struct Tower {
-	grid: VecGrid<bool>,
+	grid: BitGrid,
 	height: usize,
 	height_sum: usize,
 	rocks_count: usize,
@@ -30,7 +29,7 @@ fn main() {
 impl Tower {
 	fn new() -> Self {
 		Tower {
-			grid: VecGrid::new(),
+			grid: BitGrid::new(0, TOWER_WIDTH),
 			height: 0,
 			height_sum: 0,
 			rocks_count: 0,
@@ -48,13 +47,13 @@ impl Tower {
 		while tower.rocks_count < limit {
 			// left/right
 			let (mov_n, mov) = jets_iter.next().unwrap();
-			if let Ok(mov) = tower.check_move(shape, pos, mov) {
-				pos = pos.add_signed(mov);
+			if let Some(pos_new) = tower.try_move(shape, pos, mov) {
+				pos = pos_new;
 			}
 
 			// down
-			if let Ok(mov) = tower.check_move(shape, pos, DOWN) {
-				pos = pos.add_signed(mov);
+			if let Some(pos_new) = tower.try_move(shape, pos, DOWN) {
+				pos = pos_new;
 			} else {
 				tower.place_shape(shape, pos);
 				tower.maybe_fast_advance(limit, shape_n, mov_n);
@@ -75,33 +74,28 @@ impl Tower {
 	}
 
 	fn reserve_for_height(&mut self, height: usize) {
-		while self.grid.len() < height {
-			self.grid.push(vec![false; TOWER_WIDTH]);
+		if self.grid.height() < height {
+			self.grid.resize(height);
 		}
 	}
 
-	fn check_move(&self, shape: &Shape, pos: Point, mov: Point<isize>) -> Result<Point<isize>, ()> {
-		let pos_new: Point<isize> = Point::from(pos) + mov;
+	/// the position after the move, or None if it hits the walls, the floor or other rocks
+	fn try_move(&self, shape: &Shape, pos: Point, mov: Point<isize>) -> Option<Point> {
+		let pos_new = pos.checked_add_signed(mov)?;
 
-		if pos_new.y < 0 || pos_new.x < 0 {
-			return Err(());
-		}
-
-		let pos_new: Point = pos_new.into();
-
-		for shape_point in shape.iter() {
-			let point = pos_new + shape_point;
-			if point.x >= TOWER_WIDTH || self.grid[point] == true {
-				return Err(());
+		for (dy, bits) in shape.rows().enumerate() {
+			let bits = bits << pos_new.x;
+			if !self.grid.fits(bits) || self.grid.overlaps(pos_new.y + dy, bits) {
+				return None;
 			}
 		}
 
-		Ok(mov)
+		Some(pos_new)
 	}
 
 	fn place_shape(&mut self, shape: &Shape, pos: Point) {
-		for shape_point in shape.iter() {
-			self.grid[pos + shape_point] = true;
+		for (dy, bits) in shape.rows().enumerate() {
+			self.grid.or_row(pos.y + dy, bits << pos.x);
 		}
 
 		self.height = self.height.max(pos.y + shape.height());
@@ -125,9 +119,8 @@ impl Tower {
 
 	fn get_state(&self, shape_n: usize, mov_n: usize) -> Option<State> {
 		if self.height >= 20 {
-			let top: Vec<bool> = self.grid[self.height - 20..self.height].iter()
-				.flatten()
-				.map(|v| *v)
+			let top: Vec<u64> = (self.height - 20..self.height)
+				.map(|y| self.grid.row(y))
 				.collect();
 
 			Some((shape_n, mov_n, top))
@@ -138,48 +131,46 @@ impl Tower {
 } // impl Tower
 
 fn parse_input() -> Vec<Point<isize>> {
-	let line = aoc::input::read_lines("day17").next().unwrap();
-	line.chars().map(|ch| match ch {
-		'<' => LEFT,
-		'>' => RIGHT,
-		_ => panic!()
-	}).collect()
+	let input = aoc::input::read_bytes(aoc::input::stdin_or("day17"));
+	input.trim_ascii().iter()
+		.map(|&b| Direction::try_from(b).unwrap().to_point())
+		.collect()
 }
 
 fn create_shapes() -> Vec<Shape> {
-	vec![
-		Shape(vec![
+	[
+		vec![
 			vec![true, true, true, true]
-		]),
-		Shape(vec![
+		],
+		vec![
 			vec![false, true, false],
 			vec![true, true, true],
 			vec![false, true, false]
-		]),
-		Shape(vec![
+		],
+		vec![
 			vec![true, true, true],
 			vec![false, false, true],
 			vec![false, false, true]
-		]),
-		Shape(vec![
+		],
+		vec![
 			vec![true],
 			vec![true],
 			vec![true],
 			vec![true]
-		]),
-		Shape(vec![
+		],
+		vec![
 			vec![true, true],
 			vec![true, true]
-		])
-	]
+		]
+	].into_iter().map(|rows| Shape(BitGrid::from_rows(rows))).collect()
 }
 
 impl Shape {
-	fn iter(&self) -> impl Iterator<Item = Point> + '_ {
-		self.0.iter_grid().filter(|(_, v)| **v).map(|(p, _)| p)
+	fn rows(&self) -> impl Iterator<Item = u64> + '_ {
+		(0..self.0.height()).map(|y| self.0.row(y))
 	}
 
 	fn height(&self) -> usize {
-		self.0.len()
+		self.0.height()
 	}
 }
\ No newline at end of file
@@ -1,8 +1,14 @@
 use adventofcode2022 as aoc;
+use aoc::input::{InputSource, Pattern};
 use Material::*;
 
 const MAX_TIME_PART1: i32 = 24;
 const MAX_TIME_PART2: i32 = 32;
+const BLUEPRINT_PATTERN: Pattern = Pattern::new("Blueprint {}: \
+	Each ore robot costs {} ore. \
+	Each clay robot costs {} ore. \
+	Each obsidian robot costs {} ore and {} clay. \
+	Each geode robot costs {} ore and {} obsidian.");
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 enum Material {
@@ -22,7 +28,7 @@ struct State {
 }
 
 fn main() {
-	let blueprints = parse_input("day19");
+	let blueprints = parse_input(aoc::input::stdin_or("day19"));
 	println!("Part 1: quality levels sum {}", part1(&blueprints));
 	println!("Part 2: quality levels sum {}", part2(&blueprints));
 }
@@ -153,17 +159,18 @@ impl State {
 	}
 }
 
-fn parse_input(day_xx: &str) -> Vec<Blueprint> {
+fn parse_input(day_xx: impl InputSource) -> Vec<Blueprint> {
 	aoc::input::read_lines(day_xx)
 		.map(|line| {
 			let mut cost = [[0; 4]; 4];
-			let words: Vec<&str> = line.split(" ").collect();
-			cost[Ore as usize][Ore as usize] = words[6].parse().unwrap();
-			cost[Clay as usize][Ore as usize] = words[12].parse().unwrap();
-			cost[Obsidian as usize][Ore as usize] = words[18].parse().unwrap();
-			cost[Obsidian as usize][Clay as usize] = words[21].parse().unwrap();
-			cost[Geode as usize][Ore as usize] = words[27].parse().unwrap();
-			cost[Geode as usize][Obsidian as usize] = words[30].parse().unwrap();
+			let (_id, ore_ore, clay_ore, obsidian_ore, obsidian_clay, geode_ore, geode_obsidian): (u32, _, _, _, _, _, _)
+				= BLUEPRINT_PATTERN.parse::<Blueprint, _>(&line).unwrap();
+			cost[Ore as usize][Ore as usize] = ore_ore;
+			cost[Clay as usize][Ore as usize] = clay_ore;
+			cost[Obsidian as usize][Ore as usize] = obsidian_ore;
+			cost[Obsidian as usize][Clay as usize] = obsidian_clay;
+			cost[Geode as usize][Ore as usize] = geode_ore;
+			cost[Geode as usize][Obsidian as usize] = geode_obsidian;
 			Blueprint(cost)
 		})
 		.collect()
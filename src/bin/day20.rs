@@ -1,9 +1,10 @@
 use adventofcode2022 as aoc;
+use aoc::input::InputSource;
 
 const DECRYPTION_KEY: i64 = 811589153;
 
 fn main() {
-	let nums = parse_input("day20");
+	let nums = parse_input(aoc::input::stdin_or("day20"));
 	println!("Part 1: sum {}", solve(&nums, 1));
 
 	let nums: Vec<i64> = nums.into_iter().map(|n| n * DECRYPTION_KEY).collect();
@@ -48,6 +49,6 @@ fn pos_add(pos: usize, mov: i64, len: usize) -> usize {
 	}
 }
 
-fn parse_input(day_xx: &str) ->  Vec<i64> {
-	aoc::input::parse_lines::<i64>(day_xx).collect()
+fn parse_input(day_xx: impl InputSource) ->  Vec<i64> {
+	aoc::input::scan_ints::<i64>(&aoc::input::read_bytes(day_xx)).collect()
 }
@@ -1,8 +1,12 @@
 use adventofcode2022 as aoc;
+use aoc::input::{InputSource, Pattern};
 use std::str::FromStr;
 use std::collections::HashMap;
 use Op::*;
 
+const MONKEY_PATTERN: Pattern = Pattern::new("{}: {}");
+const OPERATION_PATTERN: Pattern = Pattern::new("{} {} {}");
+
 struct Monkey(String, Op);
 
 #[derive(Clone)]
@@ -15,7 +19,7 @@ enum Op {
 }
 
 fn main() {
-	let mut unsolved = parse_input("day21");
+	let mut unsolved = parse_input(aoc::input::stdin_or("day21"));
 	let solved = extract_solved(&mut unsolved);
 	println!("Part 1: root = {}", part1(unsolved.clone(), solved.clone()));
 	println!("Part 2: humn = {}", part2(unsolved, solved));
@@ -130,7 +134,7 @@ fn solve_cycle_inverse(unsolved: &mut HashMap<String, Op>, solved: &mut HashMap<
 	});
 }
 
-fn parse_input(day_xx: &str) -> HashMap<String, Op> {
+fn parse_input(day_xx: impl InputSource) -> HashMap<String, Op> {
 	aoc::input::parse_lines::<Monkey>(day_xx).map(|m| (m.0, m.1)).collect()
 }
 
@@ -147,15 +151,14 @@ impl FromStr for Monkey {
 	type Err = aoc::input::ParseAoCInputError<Op>;
 
 	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		let monkey = s[..4].to_string();
-		let words: Vec<&str> = s[6..].split_ascii_whitespace().collect();
-		let op = match words.get(1) {
-			Some(&"+") => Sum(words[0].to_string(), words[2].to_string()),
-			Some(&"-") => Sub(words[0].to_string(), words[2].to_string()),
-			Some(&"*") => Mul(words[0].to_string(), words[2].to_string()),
-			Some(&"/") => Div(words[0].to_string(), words[2].to_string()),
-			None => Num(words[0].parse()?),
-			_ => panic!()
+		let (monkey, job): (String, String) = MONKEY_PATTERN.parse(s)?;
+		let op = match OPERATION_PATTERN.parse::<Op, (String, char, String)>(&job) {
+			Ok((m1, '+', m2)) => Sum(m1, m2),
+			Ok((m1, '-', m2)) => Sub(m1, m2),
+			Ok((m1, '*', m2)) => Mul(m1, m2),
+			Ok((m1, '/', m2)) => Div(m1, m2),
+			Ok(_) => return Err(aoc::input::ParseAoCInputError::new(s)),
+			Err(_) => Num(job.parse()?),
 		};
 		Ok(Monkey(monkey, op))
 	}
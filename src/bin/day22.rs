@@ -1,15 +1,14 @@
 use adventofcode2022 as aoc;
-use aoc::space_2D::{Grid, VecGrid};
-use std::collections::BTreeMap;
-use Orientation::*;
+use aoc::input::InputSource;
+use aoc::space_2D::{VecGrid, Grid, TopoGrid, Topology, WrapNonBlank, Stitched, Direction};
 
 type Point = aoc::space_2D::Point<isize>;
-type WrapFn = dyn Fn(&VecGrid<u8>, Traveler) -> Traveler;
+type Map = VecGrid<u8>;
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 struct Traveler {
 	pos: Point,
-	orientation: Orientation
+	orientation: Direction
 }
 
 #[derive(Clone)]
@@ -18,52 +17,37 @@ struct Indications {
 	pos: usize,
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-enum Orientation {
-	RIGHT, DOWN, LEFT, UP
-}
-
 fn main() {
-	let (map, dirs) = parse_input("day22");
-	println!("Part1: result {}", solve(&map, dirs.clone(), &wrap));
-
-	let mappings = create_cube_wrap();
-	let wrap_cube = move |_: &_, traveler| mappings[&traveler];
-	println!("Part2: result {}", solve(&map, dirs, &wrap_cube));
+	let (map, dirs) = parse_input(aoc::input::stdin_or("day22"));
+	println!("Part1: result {}", solve(&TopoGrid::new(map.clone(), WrapNonBlank::new(b' ')), dirs.clone()));
+	println!("Part2: result {}", solve(&TopoGrid::new(map, create_cube_wrap()), dirs));
 }
 
-fn solve(map: &VecGrid<u8>, mut indications: Indications, wrap_fn: &WrapFn) -> isize {
-	let col_start = map[0].iter().position(|ch| *ch == b'.').unwrap() as isize;
-	let mut traveler = Traveler {pos: (0, col_start).into(), orientation: RIGHT};
+fn solve<Tp: Topology<Map>>(map: &TopoGrid<Map, Tp>, mut indications: Indications) -> isize {
+	let col_start = map.grid()[0].iter().position(|ch| *ch == b'.').unwrap() as isize;
+	let mut traveler = Traveler {pos: (0, col_start).into(), orientation: Direction::East};
 
 	while !indications.done() {
 		if let Some(steps) = indications.get_next_move_steps() {
 			for _ in 0..steps {
-				let next = move_maybe_wrap(map, &traveler, wrap_fn);
-				if map[next.pos] == b'#' {
+				let (pos, dir) = map.step(traveler.pos, traveler.orientation.to_point()).unwrap();
+				if map[pos] == b'#' {
 					break;
 				}
-				traveler = next;
+				traveler = Traveler {pos, orientation: Direction::try_from(dir).unwrap()};
 			}
 		}
 
 		if let Some(turn) = indications.get_next_turn() {
-			traveler.orientation = traveler.orientation.turn(turn);
+			traveler.orientation = match turn {
+				'R' => traveler.orientation.turn_right(),
+				'L' => traveler.orientation.turn_left(),
+				_ => panic!("unexpected turn {}", turn)
+			};
 		}
 	}
 
-	1000 * (traveler.pos.y + 1) + 4 * (traveler.pos.x + 1) + traveler.orientation as isize
-}
-
-fn move_maybe_wrap(map: &VecGrid<u8>, traveler: &Traveler, wrap_fn: &WrapFn) -> Traveler {
-	let mut traveler = traveler.clone();
-	traveler.pos += traveler.orientation.get_move_dir();
-
-	if map.get_point(traveler.pos).is_none() || map[traveler.pos] == b' ' {
-		traveler = wrap_fn(&map, traveler);
-	}
-
-	traveler
+	1000 * (traveler.pos.y + 1) + 4 * (traveler.pos.x + 1) + traveler.orientation.facing().unwrap() as isize
 }
 
 impl Indications {
@@ -95,91 +79,32 @@ impl Indications {
 	}
 }
 
-impl Orientation {
-	fn get_move_dir(&self) -> Point {
-		Point::from(match *self {
-			RIGHT => (0, 1),
-			DOWN  => (1, 0),
-			LEFT  => (0, -1),
-			UP    => (-1, 0)
-		})
-	}
-
-	fn turn(&self, turn: char) -> Orientation {
-		match (self, turn) {
-			(RIGHT, 'R') => DOWN,
-			(RIGHT, 'L') => UP,
-			(DOWN, 'R')  => LEFT,
-			(DOWN, 'L')  => RIGHT,
-			(LEFT, 'R')  => UP,
-			(LEFT, 'L')  => DOWN,
-			(UP, 'R')    => RIGHT,
-			(UP, 'L')    => LEFT,
-			_ => panic!()
-		}
-	}
-}
-
-fn wrap(map: &VecGrid<u8>, mut traveler: Traveler) -> Traveler {
-	let (y, x) = (traveler.pos.y as usize, traveler.pos.x as usize);
-	let pos = match traveler.orientation {
-		RIGHT => (y, map[y].iter().position(|ch| *ch != b' ').unwrap()),
-		DOWN  => (map.iter().position(|row| row[x] != b' ').unwrap(), x),
-		LEFT  => (y, map[y].iter().rposition(|ch| *ch != b' ').unwrap()),
-		UP    => (map.iter().rposition(|row| row[x] != b' ').unwrap(), x)
-	};
-	traveler.pos = (pos.0 as isize, pos.1 as isize).into();
-	traveler
-}
-
-fn create_cube_wrap() -> BTreeMap<Traveler, Traveler> {
-	let mut mappings: BTreeMap<Traveler, Traveler> = BTreeMap::new();
-	let tr = |y, x, orientation| Traveler {pos: Point::from((y, x)), orientation};
-
-	for n in 0..50 {
-		mappings.insert(tr(n, 49, LEFT), tr(149 - n, 0, RIGHT));
-		mappings.insert(tr(149 - n, -1, LEFT), tr(n, 50, RIGHT));
-
-		mappings.insert(tr(-1, 50 + n, UP), tr(150 + n, 0, RIGHT));
-		mappings.insert(tr(150 + n, -1, LEFT), tr(0, 50 + n, DOWN));
-		
-		mappings.insert(tr(-1, 100 + n, UP), tr(199, n, UP));
-		mappings.insert(tr(200, n, DOWN), tr(0, 100 + n, DOWN));
-
-		mappings.insert(tr(n, 150, RIGHT), tr(149 - n, 99, LEFT));
-		mappings.insert(tr(149 - n, 100, RIGHT), tr(n, 149, LEFT));
-
-		mappings.insert(tr(50, 100 + n, DOWN), tr(50 + n, 99, LEFT));
-		mappings.insert(tr(50 + n, 100, RIGHT), tr(49, 100 + n, UP));
-
-		mappings.insert(tr(50 + n, 49, LEFT), tr(100, n, DOWN));
-		mappings.insert(tr(99, n, UP), tr(50 + n, 50, RIGHT));
-
-		mappings.insert(tr(150, 50 + n, DOWN), tr(150 + n, 49, LEFT));
-		mappings.insert(tr(150 + n, 50, RIGHT), tr(149, 50 + n, UP));
-	}
-
-	mappings
+/// stitch the edges of the faces of the cube, for the shape of the input:
+///  .##
+///  .#.
+///  ##.
+///  #..
+fn create_cube_wrap() -> Stitched {
+	let p = |y, x| Point::from((y, x));
+	let (right, down, left, up) = (Direction::East.into(), Direction::South.into(), Direction::West.into(), Direction::North.into());
+
+	Stitched::new()
+		.stitch_edge(p(0, 50), down, left, p(149, 0), up, right, 50)
+		.stitch_edge(p(0, 50), right, up, p(150, 0), down, right, 50)
+		.stitch_edge(p(0, 100), right, up, p(199, 0), right, up, 50)
+		.stitch_edge(p(0, 149), down, right, p(149, 99), up, left, 50)
+		.stitch_edge(p(49, 100), right, down, p(50, 99), down, left, 50)
+		.stitch_edge(p(50, 50), down, left, p(100, 0), right, down, 50)
+		.stitch_edge(p(149, 50), right, down, p(150, 49), down, left, 50)
 }
 
-fn parse_input(day_xx: &str) -> (VecGrid<u8>, Indications) {
-	let mut lines = aoc::input::read_lines(day_xx);
-
-	let mut max_len = 0;
-	let mut map = lines.by_ref().map_while(|line| {
-		if !line.is_empty() {
-			max_len = max_len.max(line.len());
-			Some(line.into_bytes())
-		} else {
-			None
-		}
-	}).collect::<Vec<_>>();
-
-	for row in &mut map {
-		row.resize(max_len, b' ');
-	}
-
-	let dirs = lines.next().unwrap();
+fn parse_input(day_xx: impl InputSource) -> (Map, Indications) {
+	let mut blocks = aoc::input::read_blocks(day_xx);
+	let map = aoc::input::GridLoader::new()
+		.padding(b' ')
+		.load_lines(&blocks.next().unwrap(), |b| b)
+		.grid;
+	let dirs = blocks.next().unwrap().remove(0);
 
 	(map, Indications {str: dirs, pos: 0})
 }
@@ -191,6 +116,6 @@ mod tests {
 	#[test]
 	fn test_part1() {
 		let (map, dirs) = parse_input("day22-test");
-		assert_eq!(solve(&map, dirs, &wrap), 6032);
+		assert_eq!(solve(&TopoGrid::new(map, WrapNonBlank::new(b' ')), dirs), 6032);
 	}
 }
\ No newline at end of file
@@ -1,17 +1,14 @@
 use adventofcode2022 as aoc;
-use std::collections::{BTreeSet, BTreeMap};
-use Dir::*;
+use aoc::input::InputSource;
+use aoc::space_2D::{BTreeGrid, Grid as GridTrait, Direction};
+use std::collections::BTreeMap;
+use Direction::*;
 
-type Point = aoc::space_2D::Point<isize>;
-type Grid = BTreeSet<Point>;
-
-#[derive(Clone, Copy)]
-enum Dir {
-	North, East, South, West
-}
+type Point = aoc::space_2D::Point<i16>;
+type Grid = BTreeGrid<bool, i16>;
 
 fn main() {
-	let grid = parse_input("day23");
+	let grid = parse_input(aoc::input::stdin_or("day23"));
 	println!("Part 1: empty spaces {}", part1(grid.clone()));
 	println!("Part 2: {} rounds", part2(grid));
 }
@@ -26,8 +23,7 @@ fn part1(mut grid: Grid) -> isize {
 		sides_order.rotate_left(1);
 	}
 
-	let (min, max) = get_minmax_points(&grid);
-	(max.x - min.x + 1) * (max.y - min.y + 1) - grid.len() as isize
+	(grid.bounds().unwrap().area() - grid.len()) as isize
 }
 
 fn part2(mut grid: Grid) -> isize {
@@ -48,39 +44,28 @@ fn part2(mut grid: Grid) -> isize {
 	round
 }
 
-fn calc_tentative_moves(grid: &Grid, sides_order: &[Dir]) -> BTreeMap<Point, Vec<Point>> {
+fn calc_tentative_moves(grid: &Grid, sides_order: &[Direction]) -> BTreeMap<Point, Vec<Point>> {
 	let mut tentative_moves: BTreeMap<Point, Vec<Point>> = BTreeMap::new();
 
-	for elf in grid {
-		let counts: Vec<_> = sides_order.iter()
-			.map(|side| (*side, count_elves(&grid, *elf, *side)))
-			.collect();
-
-		let count: usize = counts.iter()
-			.map(|(_, count)| *count)
-			.sum();
+	for elf in grid.points() {
+		if !grid.neighbours_8(elf).any(|(_, p)| grid[p]) {
+			continue;
+		}
 
-		let dir = counts.into_iter()
-			.find(|(_, count)| *count == 0)
-			.map(|(dir, _)| dir);
+		let dir = sides_order.iter()
+			.find(|side| !grid.neighbours(elf, &side_offsets(**side)).any(|(_, p)| grid[p]));
 
-		if count > 0 && dir.is_some() {
-			let next_pos = *elf + dir.unwrap().get_mov();
+		if let Some(dir) = dir {
+			let next_pos = elf.offset(dir.to_point()).unwrap();
 			tentative_moves.entry(next_pos)
 				.or_default()
-				.push(*elf);
+				.push(elf);
 		}
 	}
 
 	tentative_moves
 }
 
-fn count_elves(grid: &BTreeSet<Point>, elf: Point, side: Dir) -> usize {
-	side.get_points().into_iter()
-		.filter(|p| grid.contains(&(elf + *p)))
-		.count()
-}
-
 fn discard_collistions(tentative_moves: BTreeMap<Point, Vec<Point>>) -> BTreeMap<Point, Point> {
 	tentative_moves.into_iter()
 		.filter(|(_dst, srcs)| srcs.len() == 1)
@@ -89,73 +74,29 @@ fn discard_collistions(tentative_moves: BTreeMap<Point, Vec<Point>>) -> BTreeMap
 }
 
 fn move_elves(mut grid: Grid, moves: BTreeMap<Point, Point>) -> Grid {
-	let mut grid_next = Grid::new();
+	let mut grid_next = Grid::new(false);
 
 	for (src, dst) in moves {
-		grid_next.insert(dst);
-		grid.remove(&src);
-	}
-	for elf in grid {
-		grid_next.insert(elf);
+		grid_next.set(dst, true);
+		grid.remove(src);
 	}
+	grid_next.extend(grid.iter().map(|(elf, _)| (elf, true)));
 
 	grid_next
 }
 
-fn get_minmax_points(grid: &Grid) -> (Point, Point) {
-	let mut min = (isize::MAX, isize::MAX);
-	let mut max = (isize::MIN, isize::MIN);
-
-	for elf in grid {
-		if elf.y > max.0 {
-			max.0 = elf.y;
-		}
-		if elf.y < min.0 {
-			min.0 = elf.y;
-		}
-		if elf.x > max.1 {
-			max.1 = elf.x;
-		}
-		if elf.x < min.1 {
-			min.1 = elf.x;
-		}
-	}
-
-	(min.into(), max.into())
-}
-
-impl Dir {
-	fn get_points(&self) -> [Point; 3] {
-		match self {
-			North => [(-1, -1).into(), (-1, 0).into(), (-1, 1).into()],
-			East  => [(1, 1).into(), (0, 1).into(), (-1, 1).into()],
-			South => [(1, -1).into(), (1, 0).into(), (1, 1).into()],
-			West  => [(1, -1).into(), (0, -1).into(), (-1, -1).into()]
-		}
-	}
-
-	fn get_mov(&self) -> Point {
-		match self {
-			North => (-1, 0).into(),
-			East  => (0, 1).into(),
-			South => (1, 0).into(),
-			West  => (0, -1).into()
-		}
-	}
+/// the 3 positions that must be free to move to a side
+fn side_offsets(side: Direction) -> [aoc::space_2D::Point<isize>; 3] {
+	[side.turn_left_45(), side, side.turn_right_45()].map(Direction::to_point)
 }
 
-fn parse_input(day_xx: &str) -> Grid {
-	let mut grid = BTreeSet::new();
-
-	for (y, line) in aoc::input::read_lines(day_xx).enumerate() {
-		for (x, ch) in line.chars().enumerate() {
-			if ch == '#' {
-				grid.insert(Point::from((y as isize, x as isize)));
-			}
-		}
-	}
-
-	grid
+fn parse_input(day_xx: impl InputSource) -> Grid {
+	aoc::input::GridLoader::new()
+		.markers(b"#")
+		.load(day_xx, |_| ())
+		.markers_of(b'#')
+		.map(|elf| (elf.try_convert().unwrap(), true))
+		.collect()
 }
 
 #[cfg(test)]
@@ -1,4 +1,5 @@
 use adventofcode2022 as aoc;
+use aoc::input::InputSource;
 use aoc::space_2D::Point;
 use std::collections::HashMap;
 use std::collections::VecDeque;
@@ -32,7 +33,7 @@ type StateCache = HashMap<StateCacheKey, usize>;
 type StateCacheItem = (StateCacheKey, usize);
 
 fn main() {
-	let grid =parse_input("day24");
+	let grid =parse_input(aoc::input::stdin_or("day24"));
 
 	let mut minutes = solve(&grid, grid.start_pos(), grid.end_pos(), 0);
 	println!("Part 1: min steps {}", minutes);
@@ -56,7 +57,7 @@ fn solve(grid: &Grid, start_pos: Point, goal_pos: Point, start_minute: usize) ->
 				continue;
 			}
 
-			// if minute + manhattan_dist(pos, grid.goal_adj(goal_pos)) + 1 >= min_minutes {
+			// if minute + pos.manhattan_dist(grid.goal_adj(goal_pos)) + 1 >= min_minutes {
 			// 	continue;
 			// }
 
@@ -82,10 +83,6 @@ fn better_prev_state_exists(seen_states: &StateCache, state_cache: &StateCacheIt
 	false
 }
 
-fn manhattan_dist(orig: Point, goal: Point) -> usize {
-	orig.x.abs_diff(goal.x) + orig.y.abs_diff(goal.y)
-}
-
 impl Grid {
 	fn next_positions(&self, pos: Point, minute: usize) -> impl Iterator<Item = Point> + '_ {	
 		[(0, 0), (1, 0), (-1, 0), (0, 1), (0, -1)].into_iter()
@@ -132,13 +129,10 @@ impl Grid {
 }
 
 impl Blizzard {
+	/// position along its row or column, that is a path of path_size cells
 	fn calc_move(&self, minute: usize, path_size: usize) -> usize {
 		let pos = self.pos0 + minute as i32 * self.vel;
-		if pos >= 0 {
-			pos as usize % path_size
-		} else {
-			((pos % path_size as i32) + path_size as i32) as usize
-		}
+		pos.rem_euclid(path_size as i32) as usize
 	}
 }
 
@@ -152,7 +146,7 @@ impl StateCacheKey {
 	}
 }
 
-fn parse_input(day_xx: &str) -> Grid {
+fn parse_input(day_xx: impl InputSource) -> Grid {
 	let mut grid: Grid = Default::default();
 
 	let lines = aoc::input::read_lines(day_xx).into_iter()
@@ -189,10 +183,20 @@ fn parse_input(day_xx: &str) -> Grid {
 mod tests {
 	use super::*;
 
+	/// in the example, the trip back starts at minute 18 and takes 23 minutes
 	#[test]
 	fn test_reverse() {
 		let grid = parse_input("day24-test");
-		assert_eq!(solve(&grid, grid.end_pos(), grid.start_pos(), 0), 23);
+		assert_eq!(solve(&grid, grid.end_pos(), grid.start_pos(), 18), 18 + 23);
+	}
+
+	/// a blizzard moving left or up that has crossed its path a whole number of
+	/// times is back at the start of the path, not one cell past its end
+	#[test]
+	fn test_blizzard_wrap() {
+		let blizzard = Blizzard {vel: -1, pos0: 0};
+		assert_eq!(blizzard.calc_move(6, 6), 0);
+		assert_eq!(blizzard.calc_move(13, 6), 5);
 	}
 
 	#[test]
@@ -9,7 +9,7 @@ const SNAFU_TO_DEC: [(char, i64); 5] = [('=', -2), ('-', -1), ('0', 0), ('1', 1)
 const DEC_TO_SNAFU: [(i64, (char, i64)); 6] = [(0, ('0', 0)), (1, ('1', 0)), (2, ('2', 0)), (3, ('=', 1)), (4, ('-', 1)), (5, ('0', 1))];
 
 fn main() {
-	let sum = aoc::input::parse_lines::<Snafu>("day25").fold(0, |acc, n| acc + n.0);
+	let sum = aoc::input::parse_lines::<Snafu>(aoc::input::stdin_or("day25")).fold(0, |acc, n| acc + n.0);
 	println!("SNAFU sum: {}", Snafu(sum));
 }
 
@@ -0,0 +1,14 @@
+use adventofcode2022 as aoc;
+
+/// regenerate the manifest of the input files, used to verify them when
+/// AOC_VERIFY_INPUT is set. Run it after adding or updating an input file
+fn main() {
+	let dir = aoc::input::input_dir();
+	match aoc::input::write_manifest(&dir) {
+		Ok(count) => println!("{}: {} files", dir.join(aoc::input::MANIFEST_FILE).display(), count),
+		Err(e) => {
+			eprintln!("{}", e);
+			std::process::exit(1);
+		},
+	}
+}
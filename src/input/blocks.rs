@@ -0,0 +1,138 @@
+use super::{InputSource, InputError, TryResult, try_read_lines};
+use std::str::FromStr;
+use std::error::Error;
+use std::fmt;
+
+/// like FromStr, but for records that span multiple lines
+pub trait FromLines: Sized {
+	type Err;
+	fn from_lines(lines: &[String]) -> Result<Self, Self::Err>;
+}
+
+/// a block of numbers, one per line, like the elves' calories of day01
+impl<T: FromStr> FromLines for Vec<T> {
+	type Err = T::Err;
+
+	fn from_lines(lines: &[String]) -> Result<Self, Self::Err> {
+		lines.iter().map(|l| l.parse()).collect()
+	}
+}
+
+/// Error returned by parse_blocks_safe. Block and line numbers start at 1
+#[derive(Debug)]
+pub struct ParseBlockError<E> {
+	/// number of the block that failed to parse
+	pub block: usize,
+	/// line where the block starts
+	pub line: usize,
+	pub err: E,
+}
+
+impl<E: fmt::Debug> fmt::Display for ParseBlockError<E> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "block {} (line {}): {:?}", self.block, self.line, self.err)
+	}
+}
+
+impl<E: fmt::Debug> Error for ParseBlockError<E> {}
+
+/// groups the lines in blocks separated by empty lines, returning also the
+/// number of the first line of each block
+struct Blocks<I> {
+	lines: I,
+	line_num: usize,
+}
+
+impl<I> Iterator for Blocks<I>
+where
+	I: Iterator<Item = TryResult<String>>
+{
+	type Item = TryResult<(usize, Vec<String>)>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let mut block = Vec::new();
+		let mut first_line = 0;
+
+		for line in self.lines.by_ref() {
+			self.line_num += 1;
+			let line = match line {
+				Ok(line) => line,
+				Err(err) => return Some(Err(err)),
+			};
+
+			match (line.is_empty(), block.is_empty()) {
+				(true, true) => continue,  // skip consecutive empty lines
+				(true, false) => break,
+				(false, true) => first_line = self.line_num,
+				(false, false) => (),
+			}
+			block.push(line);
+		}
+
+		match block.is_empty() {
+			true => None,
+			false => Some(Ok((first_line, block)))
+		}
+	}
+}
+
+fn try_read_numbered_blocks(day_xx: impl InputSource)
+	-> TryResult<impl Iterator<Item = TryResult<(usize, Vec<String>)>>>
+{
+	Ok(Blocks { lines: try_read_lines(day_xx)?, line_num: 0 })
+}
+
+/// like read_blocks, but return an error instead of panicking
+pub fn try_read_blocks(day_xx: impl InputSource)
+	-> TryResult<impl Iterator<Item = TryResult<Vec<String>>>>
+{
+	Ok(try_read_numbered_blocks(day_xx)?.map(|block| block.map(|(_, lines)| lines)))
+}
+
+/// read the input in blocks of lines separated by empty lines
+pub fn read_blocks(day_xx: impl InputSource) -> impl Iterator<Item = Vec<String>> {
+	try_read_blocks(day_xx)
+		.unwrap_or_else(|e| panic!("{}", e))
+		.map(|block| block.unwrap_or_else(|e| panic!("{}", e)))
+}
+
+/// read the input in blocks of lines separated by empty lines, parsing each
+/// one with FromLines. Panics with the block number if one can't be parsed
+pub fn parse_blocks<T>(day_xx: impl InputSource) -> impl Iterator<Item = T>
+where
+	T: FromLines,
+	T::Err: fmt::Debug
+{
+	parse_blocks_safe(day_xx).map(|block| block.unwrap_or_else(|e| panic!("{}", e)))
+}
+
+pub fn parse_blocks_safe<T>(day_xx: impl InputSource)
+	-> impl Iterator<Item = Result<T, ParseBlockError<T::Err>>>
+where
+	T: FromLines
+{
+	try_read_numbered_blocks(day_xx)
+		.unwrap_or_else(|e| panic!("{}", e))
+		.enumerate()
+		.map(|(i, block)| {
+			let (line, lines) = block.unwrap_or_else(|e| panic!("{}", e));
+			T::from_lines(&lines).map_err(|err| ParseBlockError { block: i + 1, line, err })
+		})
+}
+
+/// like parse_blocks, but return an error instead of panicking. Parse errors
+/// are reported as InputError::Parse, with the line where the block starts
+pub fn try_parse_blocks<T>(day_xx: impl InputSource)
+	-> TryResult<impl Iterator<Item = TryResult<T>>>
+where
+	T: FromLines,
+	T::Err: fmt::Debug
+{
+	let path = day_xx.name();
+	Ok(try_read_numbered_blocks(day_xx)?.enumerate().map(move |(i, block)| {
+		let (line, lines) = block?;
+		T::from_lines(&lines).map_err(|err| {
+			InputError::Parse { path: path.clone(), line, msg: format!("block {}: {:?}", i + 1, err) }
+		})
+	}))
+}
@@ -0,0 +1,188 @@
+use super::{InputSource, InputError, TryResult};
+use super::manifest::check_contents;
+use std::fs::File;
+use std::io::{self, Read};
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::str;
+
+/// The whole input in a single contiguous buffer, returned by read_bytes.
+///
+/// Files are mapped in memory if the "mmap" feature is enabled (the default),
+/// so huge inputs are not copied. Other sources are read to a Vec<u8>.
+pub struct InputBytes {
+	path: PathBuf,
+	buf: Buf,
+}
+
+enum Buf {
+	Owned(Vec<u8>),
+	#[cfg(feature = "mmap")]
+	Mapped(memmap2::Mmap),
+}
+
+fn buf_bytes(buf: &Buf) -> &[u8] {
+	match buf {
+		Buf::Owned(vec) => vec,
+		#[cfg(feature = "mmap")]
+		Buf::Mapped(mmap) => mmap,
+	}
+}
+
+impl InputBytes {
+	pub fn as_bytes(&self) -> &[u8] {
+		buf_bytes(&self.buf)
+	}
+
+	/// the input as str, panicking if it's not valid UTF-8
+	pub fn as_str(&self) -> &str {
+		self.try_as_str().unwrap_or_else(|e| panic!("{}", e))
+	}
+
+	/// like as_str, but return an error with the line of the first invalid byte
+	pub fn try_as_str(&self) -> TryResult<&str> {
+		let bytes = self.as_bytes();
+		str::from_utf8(bytes).map_err(|e| {
+			let line = 1 + bytes[..e.valid_up_to()].iter().filter(|&&b| b == b'\n').count();
+			InputError::Decode { path: self.path.clone(), line }
+		})
+	}
+
+	/// name of the source, like in the errors
+	pub fn path(&self) -> &Path {
+		&self.path
+	}
+}
+
+impl Deref for InputBytes {
+	type Target = [u8];
+
+	fn deref(&self) -> &[u8] {
+		self.as_bytes()
+	}
+}
+
+impl AsRef<[u8]> for InputBytes {
+	fn as_ref(&self) -> &[u8] {
+		self.as_bytes()
+	}
+}
+
+#[cfg(feature = "mmap")]
+fn read_file(path: &Path) -> io::Result<Buf> {
+	let file = File::open(path)?;
+	// SAFETY: the input files are not expected to be modified while running.
+	// If they were, the worst case would be reading garbage, like with any race
+	match unsafe { memmap2::Mmap::map(&file) } {
+		Ok(mmap) => Ok(Buf::Mapped(mmap)),
+		Err(_) => read_to_vec(file),  // i.e. special files that can't be mapped
+	}
+}
+
+#[cfg(not(feature = "mmap"))]
+fn read_file(path: &Path) -> io::Result<Buf> {
+	read_to_vec(File::open(path)?)
+}
+
+fn read_to_vec(mut reader: impl Read) -> io::Result<Buf> {
+	let mut vec = Vec::new();
+	reader.read_to_end(&mut vec)?;
+	Ok(Buf::Owned(vec))
+}
+
+/// like read_bytes, but return an error instead of panicking
+pub fn try_read_bytes(day_xx: impl InputSource) -> TryResult<InputBytes> {
+	let path = day_xx.name();
+	let buf = match day_xx.file() {
+		Some(file) => {
+			let buf = read_file(&file).map_err(|e| InputError::from_io(&path, None, e))?;
+			check_contents(&file, buf_bytes(&buf))?;
+			buf
+		},
+		None => day_xx.open().and_then(read_to_vec).map_err(|e| InputError::from_io(&path, None, e))?,
+	};
+	Ok(InputBytes { path, buf })
+}
+
+/// read the whole input at once, i.e. for inputs of a single huge line.
+/// Use `.as_str()` to get it as str, or any slice method to get the bytes
+pub fn read_bytes(day_xx: impl InputSource) -> InputBytes {
+	try_read_bytes(day_xx).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// integer types that can be scanned directly from bytes with scan_ints and
+/// parse_int, without going through str::parse
+pub trait ScanInt: Copy {
+	/// if negative numbers are accepted, or '-' is taken as any other separator
+	const SIGNED: bool;
+
+	/// build the number from its ASCII digits, or None if it overflows
+	fn from_digits(negative: bool, digits: &[u8]) -> Option<Self>;
+}
+
+macro_rules! impl_scan_int {
+	($signed:expr; $($ty:ty),+) => {$(
+		impl ScanInt for $ty {
+			const SIGNED: bool = $signed;
+
+			fn from_digits(negative: bool, digits: &[u8]) -> Option<Self> {
+				digits.iter().try_fold(0 as $ty, |num, &d| {
+					let d = (d - b'0') as $ty;
+					match negative {
+						false => num.checked_mul(10)?.checked_add(d),
+						true => num.checked_mul(10)?.checked_sub(d),
+					}
+				})
+			}
+		}
+	)+};
+}
+
+impl_scan_int!(false; u8, u16, u32, u64, u128, usize);
+impl_scan_int!(true; i8, i16, i32, i64, i128, isize);
+
+/// parse the bytes as an integer, that must be made only of digits and an
+/// optional leading '-' for signed types. Returns None if invalid or too big
+pub fn parse_int<T: ScanInt>(bytes: &[u8]) -> Option<T> {
+	let (negative, digits) = match bytes {
+		[b'-', digits @ ..] if T::SIGNED => (true, digits),
+		digits => (false, digits),
+	};
+	match !digits.is_empty() && digits.iter().all(u8::is_ascii_digit) {
+		true => T::from_digits(negative, digits),
+		false => None,
+	}
+}
+
+/// Iterator over the integers found in some bytes, returned by scan_ints
+pub struct ScanInts<'a, T> {
+	bytes: &'a [u8],
+	pos: usize,
+	data_type: PhantomData<T>,
+}
+
+impl<'a, T: ScanInt> Iterator for ScanInts<'a, T> {
+	type Item = T;
+
+	fn next(&mut self) -> Option<T> {
+		let bytes = self.bytes;
+		let start = self.pos + bytes[self.pos..].iter().position(u8::is_ascii_digit)?;
+		let end = start + bytes[start..].iter().position(|b| !b.is_ascii_digit()).unwrap_or(bytes.len() - start);
+		self.pos = end;
+
+		let negative = T::SIGNED && start > 0 && bytes[start - 1] == b'-';
+		let num = T::from_digits(negative, &bytes[start..end]);
+		Some(num.unwrap_or_else(|| {
+			let text = String::from_utf8_lossy(&bytes[start - negative as usize..end]);
+			panic!("Number '{}' at byte {} doesn't fit in type '{}'", text, start, std::any::type_name::<T>())
+		}))
+	}
+}
+
+/// iterate over all the integers in the bytes, ignoring anything else, like
+/// `scan_ints::<i64>(&read_bytes("day20"))`. A '-' just before the digits makes
+/// the number negative for signed types. Panics if a number doesn't fit in T
+pub fn scan_ints<T: ScanInt>(bytes: &[u8]) -> ScanInts<'_, T> {
+	ScanInts { bytes, pos: 0, data_type: PhantomData }
+}
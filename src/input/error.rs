@@ -0,0 +1,280 @@
+use std::convert::Infallible;
+use std::error::Error;
+use std::io;
+use std::marker::PhantomData;
+use std::num::{ParseIntError, ParseFloatError};
+use std::char::ParseCharError;
+use std::str::ParseBoolError;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::fmt;
+
+/// Error returned by the try_* functions when the input can't be loaded
+#[derive(Debug)]
+pub enum InputError {
+	/// the input file doesn't exist
+	NotFound { path: PathBuf },
+	/// any other I/O error while opening or reading the file
+	Io { path: PathBuf, line: Option<usize>, source: io::Error },
+	/// the line is not valid UTF-8
+	Decode { path: PathBuf, line: usize },
+	/// the line was read correctly, but it couldn't be parsed to the requested type
+	Parse { path: PathBuf, line: usize, msg: String },
+	/// the file doesn't match its entry in the input manifest, see verify_input
+	Manifest { path: PathBuf, msg: String },
+}
+
+impl InputError {
+	pub(crate) fn from_io(path: &Path, line: Option<usize>, err: io::Error) -> Self {
+		match (err.kind(), line) {
+			(io::ErrorKind::NotFound, None) => InputError::NotFound { path: path.to_path_buf() },
+			(io::ErrorKind::InvalidData, Some(line)) => InputError::Decode { path: path.to_path_buf(), line },
+			_ => InputError::Io { path: path.to_path_buf(), line, source: err },
+		}
+	}
+
+	pub(crate) fn from_parse<E: fmt::Debug>(path: &Path, line: usize, err: E) -> Self {
+		InputError::Parse { path: path.to_path_buf(), line, msg: format!("{:?}", err) }
+	}
+
+	/// path of the file that caused the error
+	pub fn path(&self) -> &Path {
+		match self {
+			InputError::NotFound { path }
+			| InputError::Io { path, .. }
+			| InputError::Decode { path, .. }
+			| InputError::Parse { path, .. }
+			| InputError::Manifest { path, .. } => path,
+		}
+	}
+}
+
+impl fmt::Display for InputError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			InputError::NotFound { path } =>
+				write!(f, "{}: input file not found", path.display()),
+			InputError::Io { path, line: None, source } =>
+				write!(f, "{}: {}", path.display(), source),
+			InputError::Io { path, line: Some(line), source } =>
+				write!(f, "{}:{}: {}", path.display(), line, source),
+			InputError::Decode { path, line } =>
+				write!(f, "{}:{}: line is not valid UTF-8", path.display(), line),
+			InputError::Parse { path, line, msg } =>
+				write!(f, "{}:{}: {}", path.display(), line, msg),
+			InputError::Manifest { path, msg } =>
+				write!(f, "{}: doesn't match the input manifest: {}", path.display(), msg),
+		}
+	}
+}
+
+impl Error for InputError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			InputError::Io { source, .. } => Some(source),
+			_ => None,
+		}
+	}
+}
+
+/// Error type that can be used by `impl FromStr for MyType`
+///
+/// Apart from the message, it can carry the location of the wrong text (file,
+/// line and columns, starting at 1) and the error that caused it. When the
+/// line's text is known, it's displayed like a compiler diagnostic:
+/// ```text
+/// input/day15.txt:3:14: Line '...' doesn't match, expected ', y='
+///   |
+/// 3 | Sensor at x=2, z=18
+///   |              ^
+/// ```
+pub struct ParseAoCInputError<T> {
+	details: Box<ErrorDetails>,
+	data_type: PhantomData<T>,
+}
+
+// boxed to keep small the Results that return ParseAoCInputError
+struct ErrorDetails {
+	wrong_str: String,
+	custom_msg: Option<String>,
+	file: Option<PathBuf>,
+	line: Option<usize>,
+	line_text: Option<String>,
+	span: Option<Range<usize>>,
+	source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl<T> ParseAoCInputError<T> {
+	pub fn new(wrong_str: &str) -> Self {
+		ParseAoCInputError {
+			details: Box::new(ErrorDetails {
+				wrong_str: String::from(wrong_str),
+				custom_msg: None,
+				file: None,
+				line: None,
+				line_text: None,
+				span: None,
+				source: None,
+			}),
+			data_type: PhantomData,
+		}
+	}
+
+	pub fn new_custom(custom_msg: &str) -> Self {
+		let mut err = Self::new("");
+		err.details.custom_msg = Some(String::from(custom_msg));
+		err
+	}
+
+	/// set the column (starting at 1) of the line where the error was found
+	pub fn at_column(self, column: usize) -> Self {
+		self.with_span(column..column + 1)
+	}
+
+	/// set the range of columns (starting at 1, end excluded) with the wrong text
+	pub fn with_span(mut self, span: Range<usize>) -> Self {
+		self.details.span = Some(span);
+		self
+	}
+
+	/// set the file, number (starting at 1) and text of the line with the error.
+	/// If the columns are not set yet, they're searched in the line's text
+	pub fn with_location(mut self, file: &Path, line: usize, line_text: &str) -> Self {
+		let details = &mut self.details;
+		if details.span.is_none() && !details.wrong_str.is_empty() {
+			if let Some(pos) = line_text.find(&details.wrong_str) {
+				details.span = Some(pos + 1..pos + 1 + details.wrong_str.len());
+			}
+		}
+		details.file = Some(file.to_path_buf());
+		details.line = Some(line);
+		details.line_text = Some(String::from(line_text));
+		self
+	}
+
+	/// set the error that caused this one
+	pub fn with_source(mut self, source: impl Error + Send + Sync + 'static) -> Self {
+		self.details.source = Some(Box::new(source));
+		self
+	}
+
+	/// column (starting at 1) of the line where the error was found, if known
+	pub fn column(&self) -> Option<usize> {
+		self.details.span.as_ref().map(|span| span.start)
+	}
+
+	pub fn span(&self) -> Option<Range<usize>> {
+		self.details.span.clone()
+	}
+
+	pub fn file(&self) -> Option<&Path> {
+		self.details.file.as_deref()
+	}
+
+	pub fn line(&self) -> Option<usize> {
+		self.details.line
+	}
+
+	fn fmt_msg(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		if let Some(msg) = &self.details.custom_msg {
+			write!(f, "{}", msg)
+		} else {
+			write!(f, "Can't parse '{}' to type '{}'", &self.details.wrong_str, std::any::type_name::<T>())
+		}
+	}
+
+	fn fmt_diagnostic(&self, f: &mut fmt::Formatter, line: usize, line_text: &str) -> fmt::Result {
+		let line_num = line.to_string();
+		let margin = " ".repeat(line_num.len());
+		write!(f, "\n{} |\n{} | {}", margin, line_num, line_text)?;
+		if let Some(span) = &self.details.span {
+			let len = span.end.saturating_sub(span.start).max(1);
+			write!(f, "\n{} | {}{}", margin, " ".repeat(span.start - 1), "^".repeat(len))?;
+		}
+		Ok(())
+	}
+}
+
+impl<T> fmt::Display for ParseAoCInputError<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match (&self.details.file, self.details.line) {
+			(Some(file), Some(line)) => {
+				write!(f, "{}:{}:", file.display(), line)?;
+				if let Some(column) = self.column() {
+					write!(f, "{}:", column)?;
+				}
+				write!(f, " ")?;
+				self.fmt_msg(f)?;
+			},
+			_ => {
+				self.fmt_msg(f)?;
+				if let Some(column) = self.column() {
+					write!(f, " (column {})", column)?;
+				}
+			},
+		}
+
+		match (self.details.line, &self.details.line_text) {
+			(Some(line), Some(line_text)) => self.fmt_diagnostic(f, line, line_text),
+			_ => Ok(())
+		}
+	}
+}
+
+impl<T> fmt::Debug for ParseAoCInputError<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		<Self as fmt::Display>::fmt(self, f)
+	}
+}
+
+impl<T> Error for ParseAoCInputError<T> {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		self.details.source.as_ref().map(|err| err.as_ref() as &(dyn Error + 'static))
+	}
+}
+
+impl<T> From<ParseIntError> for ParseAoCInputError<T> {
+	fn from(err: ParseIntError) -> Self {
+		let msg = format!("ParseIntError (kind {:?}) while trying to parse to type '{}'",
+						err.kind(), std::any::type_name::<T>());
+		ParseAoCInputError::new_custom(&msg).with_source(err)
+	}
+}
+
+impl<T> From<ParseFloatError> for ParseAoCInputError<T> {
+	fn from(err: ParseFloatError) -> Self {
+		let msg = format!("ParseFloatError ({}) while trying to parse to type '{}'",
+						err, std::any::type_name::<T>());
+		ParseAoCInputError::new_custom(&msg).with_source(err)
+	}
+}
+
+impl<T> From<ParseCharError> for ParseAoCInputError<T> {
+	fn from(err: ParseCharError) -> Self {
+		let msg = format!("ParseCharError ({}) while trying to parse to type '{}'",
+						err, std::any::type_name::<T>());
+		ParseAoCInputError::new_custom(&msg).with_source(err)
+	}
+}
+
+impl<T> From<ParseBoolError> for ParseAoCInputError<T> {
+	fn from(err: ParseBoolError) -> Self {
+		let msg = format!("ParseBoolError ({}) while trying to parse to type '{}'",
+						err, std::any::type_name::<T>());
+		ParseAoCInputError::new_custom(&msg).with_source(err)
+	}
+}
+
+impl<T> From<regex::Error> for ParseAoCInputError<T> {
+	fn from(err: regex::Error) -> Self {
+		let msg = format!("Invalid regex while trying to parse to type '{}'", std::any::type_name::<T>());
+		ParseAoCInputError::new_custom(&msg).with_source(err)
+	}
+}
+
+/// allow to use parse_lines_safe with types that never fail, like String
+impl<T> From<Infallible> for ParseAoCInputError<T> {
+	fn from(err: Infallible) -> Self {
+		match err {}
+	}
+}
@@ -0,0 +1,159 @@
+use super::{InputSource, InputError, TryResult, try_read_lines};
+use crate::space_2D::{Point, VecGrid};
+use std::convert::Infallible;
+use std::fmt;
+
+/// loads the input as a 2D grid, with one row per line and one cell per byte.
+/// The grid ends at the first empty line or at the end of the input.
+///
+/// `let loaded = GridLoader::new().markers(b"SE").load("day12", |b| b);`
+#[derive(Clone, Copy, Default)]
+pub struct GridLoader<'a> {
+	markers: &'a [u8],
+	padding: Option<u8>,
+}
+
+/// grid returned by GridLoader, with the positions of the markers found
+pub struct LoadedGrid<T> {
+	pub grid: VecGrid<T>,
+	pub markers: Vec<(u8, Point)>,
+}
+
+impl<T> LoadedGrid<T> {
+	/// position of the first occurrence of the marker
+	pub fn marker(&self, marker: u8) -> Option<Point> {
+		self.markers_of(marker).next()
+	}
+
+	/// positions of all the occurrences of the marker
+	pub fn markers_of(&self, marker: u8) -> impl Iterator<Item = Point> + '_ {
+		self.markers.iter().filter(move |(m, _)| *m == marker).map(|(_, p)| *p)
+	}
+}
+
+impl<'a> GridLoader<'a> {
+	pub fn new() -> Self {
+		Default::default()
+	}
+
+	/// bytes whose positions are recorded while loading, like b"SE". They're
+	/// still passed to the mapping function like any other byte
+	pub fn markers(mut self, markers: &'a [u8]) -> Self {
+		self.markers = markers;
+		self
+	}
+
+	/// pad the rows shorter than the longest one with this byte, so the grid is
+	/// rectangular. The padding is passed to the mapping function too
+	pub fn padding(mut self, padding: u8) -> Self {
+		self.padding = Some(padding);
+		self
+	}
+
+	/// load the grid mapping each byte with `map`
+	pub fn load<T>(&self, day_xx: impl InputSource, mut map: impl FnMut(u8) -> T) -> LoadedGrid<T> {
+		let map = |b| Ok::<_, Infallible>(map(b));
+		self.try_load(day_xx, map).unwrap_or_else(|e| panic!("{}", e))
+	}
+
+	/// load the grid converting each byte with TryFrom<u8>, panicking on error
+	pub fn parse<T>(&self, day_xx: impl InputSource) -> LoadedGrid<T>
+	where
+		T: TryFrom<u8>,
+		T::Error: fmt::Debug
+	{
+		self.try_parse(day_xx).unwrap_or_else(|e| panic!("{}", e))
+	}
+
+	/// like parse, but return an error instead of panicking
+	pub fn try_parse<T>(&self, day_xx: impl InputSource) -> TryResult<LoadedGrid<T>>
+	where
+		T: TryFrom<u8>,
+		T::Error: fmt::Debug
+	{
+		self.try_load(day_xx, T::try_from)
+	}
+
+	/// load the grid mapping each byte with `map`, that can fail. Errors are
+	/// reported with the line and column of the wrong byte
+	pub fn try_load<T, E>(&self, day_xx: impl InputSource, map: impl FnMut(u8) -> Result<T, E>)
+		-> TryResult<LoadedGrid<T>>
+	where
+		E: fmt::Debug
+	{
+		let path = day_xx.name();
+		let lines = try_read_lines(day_xx)?;
+		self.load_numbered_lines(lines, map).map_err(|(line, col, err)| match err {
+			ErrorKind::Input(err) => err,
+			ErrorKind::Map(err) => InputError::Parse { path, line, msg: format!("column {}: {:?}", col, err) },
+		})
+	}
+
+	/// load the grid from already read lines, i.e. from a block of read_blocks
+	pub fn load_lines<S, T>(&self, lines: &[S], mut map: impl FnMut(u8) -> T) -> LoadedGrid<T>
+	where
+		S: AsRef<str>
+	{
+		let lines = lines.iter().map(|l| Ok(l.as_ref().to_string()));
+		let map = |b| Ok::<_, Infallible>(map(b));
+		match self.load_numbered_lines(lines, map) {
+			Ok(loaded) => loaded,
+			Err(_) => unreachable!("lines are already read and map can't fail"),
+		}
+	}
+
+	fn load_numbered_lines<T, E>(
+		&self,
+		lines: impl Iterator<Item = TryResult<String>>,
+		mut map: impl FnMut(u8) -> Result<T, E>
+	) -> Result<LoadedGrid<T>, (usize, usize, ErrorKind<E>)> {
+		let mut rows = Vec::new();
+		let mut markers = Vec::new();
+
+		for (y, line) in lines.enumerate() {
+			let line = line.map_err(|e| (y + 1, 0, ErrorKind::Input(e)))?;
+			if line.is_empty() {
+				break;
+			}
+
+			let row = line.bytes().enumerate().map(|(x, b)| {
+				if self.markers.contains(&b) {
+					markers.push((b, Point::from((y, x))));
+				}
+				map(b).map_err(|e| (y + 1, x + 1, ErrorKind::Map(e)))
+			}).collect::<Result<Vec<_>, _>>()?;
+			rows.push(row);
+		}
+
+		if let Some(padding) = self.padding {
+			let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+			for (y, row) in rows.iter_mut().enumerate() {
+				while row.len() < width {
+					let x = row.len();
+					row.push(map(padding).map_err(|e| (y + 1, x + 1, ErrorKind::Map(e)))?);
+				}
+			}
+		}
+
+		Ok(LoadedGrid { grid: rows, markers })
+	}
+}
+
+enum ErrorKind<E> {
+	Input(InputError),
+	Map(E),
+}
+
+/// load the input as a grid mapping each byte with `map`
+pub fn read_grid<T>(day_xx: impl InputSource, map: impl FnMut(u8) -> T) -> VecGrid<T> {
+	GridLoader::new().load(day_xx, map).grid
+}
+
+/// load the input as a grid converting each byte with TryFrom<u8>
+pub fn parse_grid<T>(day_xx: impl InputSource) -> VecGrid<T>
+where
+	T: TryFrom<u8>,
+	T::Error: fmt::Debug
+{
+	GridLoader::new().parse(day_xx).grid
+}
@@ -0,0 +1,190 @@
+use super::{InputSource, InputError, TryResult};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+/// name of the manifest file, in the same directory as the input files.
+///
+/// Each line has the SHA-256 digest, the number of lines and the name of a file:
+/// `<sha256 in hex> <lines> <file name>`
+pub const MANIFEST_FILE: &str = "MANIFEST";
+
+/// environment variable to enable the verification of the input files
+pub const VERIFY_INPUT_ENV: &str = "AOC_VERIFY_INPUT";
+
+static VERIFY_INPUT: RwLock<Option<bool>> = RwLock::new(None);
+
+/// enable or disable the verification of the input files against the manifest,
+/// overriding the environment variable AOC_VERIFY_INPUT
+pub fn set_verify_input(verify: bool) {
+	*VERIFY_INPUT.write().unwrap() = Some(verify);
+}
+
+/// if the input files are verified against the manifest when loaded. In order
+/// of precedence: the value set with set_verify_input, if the env variable
+/// AOC_VERIFY_INPUT is set to anything but "0", or false
+pub fn verify_input_enabled() -> bool {
+	if let Some(verify) = *VERIFY_INPUT.read().unwrap() {
+		return verify;
+	}
+	match env::var_os(VERIFY_INPUT_ENV) {
+		Some(val) => !val.is_empty() && val != "0",
+		None => false,
+	}
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Entry {
+	digest: String,
+	lines: usize,
+}
+
+impl Entry {
+	fn of(contents: &[u8]) -> Self {
+		let mut digest = String::with_capacity(64);
+		for b in Sha256::digest(contents) {
+			write!(digest, "{:02x}", b).unwrap();
+		}
+		Entry { digest, lines: count_lines(contents) }
+	}
+}
+
+/// lines as returned by read_lines, so the last one may have no '\n'
+fn count_lines(contents: &[u8]) -> usize {
+	let newlines = contents.iter().filter(|&&b| b == b'\n').count();
+	match contents.last() {
+		Some(b'\n') | None => newlines,
+		Some(_) => newlines + 1,
+	}
+}
+
+fn manifest_path(dir: &Path) -> PathBuf {
+	dir.join(MANIFEST_FILE)
+}
+
+fn read_manifest(dir: &Path) -> TryResult<HashMap<String, Entry>> {
+	let path = manifest_path(dir);
+	let text = fs::read_to_string(&path).map_err(io_err(&path))?;
+
+	text.lines().enumerate().filter(|(_, l)| !l.is_empty()).map(|(i, l)| {
+		let mut fields = l.splitn(3, ' ');
+		match (fields.next(), fields.next().and_then(|n| n.parse().ok()), fields.next()) {
+			(Some(digest), Some(lines), Some(name)) if digest.len() == 64 => {
+				Ok((name.to_string(), Entry { digest: digest.to_string(), lines }))
+			},
+			_ => Err(InputError::Parse { path: path.clone(), line: i + 1, msg: "Wrong manifest entry".to_string() }),
+		}
+	}).collect()
+}
+
+/// check the contents of a file against its entry in the manifest of its directory
+fn verify_contents(path: &Path, contents: &[u8]) -> TryResult<()> {
+	let dir = path.parent().unwrap_or(Path::new(""));
+	let name = path.file_name().unwrap_or_default().to_string_lossy();
+	let mismatch = |msg: String| InputError::Manifest { path: path.to_path_buf(), msg };
+
+	let manifest = read_manifest(dir)?;
+	let expected = manifest.get(name.as_ref())
+		.ok_or_else(|| mismatch(format!("file not listed in {}", manifest_path(dir).display())))?;
+	let found = Entry::of(contents);
+
+	match (found.lines == expected.lines, found.digest == expected.digest) {
+		(_, true) => Ok(()),
+		(false, false) => Err(mismatch(format!("expected {} lines, found {}. Is it truncated?",
+		                                       expected.lines, found.lines))),
+		(true, false) => Err(mismatch(format!("expected SHA-256 {}, found {}. Has it been edited?",
+		                                      expected.digest, found.digest))),
+	}
+}
+
+/// check a file against the manifest of its directory, even if the verification
+/// is not enabled with set_verify_input or AOC_VERIFY_INPUT
+pub fn verify_file(path: impl AsRef<Path>) -> TryResult<()> {
+	let path = path.as_ref();
+	let contents = fs::read(path).map_err(io_err(path))?;
+	verify_contents(path, &contents)
+}
+
+/// verify the contents already read of a file, if the verification is enabled
+pub(crate) fn check_contents(path: &Path, contents: &[u8]) -> TryResult<()> {
+	match verify_input_enabled() {
+		true => verify_contents(path, contents),
+		false => Ok(()),
+	}
+}
+
+/// reader returned by the input functions: the contents already read and
+/// verified if the verification is enabled, or the source opened otherwise
+pub enum CheckedReader<R> {
+	Verified(io::Cursor<Vec<u8>>),
+	Source(R),
+}
+
+impl<R: io::Read> io::Read for CheckedReader<R> {
+	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+		match self {
+			CheckedReader::Verified(cursor) => cursor.read(buf),
+			CheckedReader::Source(reader) => reader.read(buf),
+		}
+	}
+}
+
+impl<R: io::BufRead> io::BufRead for CheckedReader<R> {
+	fn fill_buf(&mut self) -> io::Result<&[u8]> {
+		match self {
+			CheckedReader::Verified(cursor) => cursor.fill_buf(),
+			CheckedReader::Source(reader) => reader.fill_buf(),
+		}
+	}
+
+	fn consume(&mut self, amt: usize) {
+		match self {
+			CheckedReader::Verified(cursor) => cursor.consume(amt),
+			CheckedReader::Source(reader) => reader.consume(amt),
+		}
+	}
+}
+
+/// open the source. If it's a file and the verification is enabled, the file is
+/// read once, verified, and the reader returns the contents already read
+pub(crate) fn open_checked<S: InputSource>(day_xx: S) -> TryResult<CheckedReader<S::Reader>> {
+	if let Some(file) = day_xx.file().filter(|_| verify_input_enabled()) {
+		let contents = fs::read(&file).map_err(io_err(&file))?;
+		check_contents(&file, &contents)?;
+		return Ok(CheckedReader::Verified(io::Cursor::new(contents)));
+	}
+	let path = day_xx.name();
+	day_xx.open().map(CheckedReader::Source).map_err(|e| InputError::from_io(&path, None, e))
+}
+
+fn io_err(path: &Path) -> impl FnOnce(io::Error) -> InputError + '_ {
+	move |e| InputError::from_io(path, None, e)
+}
+
+/// write the manifest of all the .txt files of the directory, like input_dir(),
+/// replacing the existing one. Returns the number of files listed
+pub fn write_manifest(dir: impl AsRef<Path>) -> TryResult<usize> {
+	let dir = dir.as_ref();
+	let mut names = fs::read_dir(dir).map_err(io_err(dir))?
+		.map(|entry| entry.map(|e| e.file_name().to_string_lossy().into_owned()))
+		.collect::<Result<Vec<_>, _>>()
+		.map_err(io_err(dir))?;
+	names.retain(|name| name.ends_with(".txt"));
+	names.sort();
+
+	let mut manifest = String::new();
+	for name in &names {
+		let path = dir.join(name);
+		let entry = Entry::of(&fs::read(&path).map_err(io_err(&path))?);
+		writeln!(manifest, "{} {} {}", entry.digest, entry.lines, name).unwrap();
+	}
+
+	let path = manifest_path(dir);
+	fs::write(&path, manifest).map_err(io_err(&path))?;
+	Ok(names.len())
+}
@@ -0,0 +1,390 @@
+mod error;
+mod source;
+mod blocks;
+mod pattern;
+mod grid;
+mod tokens;
+mod bytes;
+mod manifest;
+
+use std::io::BufRead;
+use std::path::Path;
+use std::str::FromStr;
+use std::fmt;
+
+pub use self::error::{InputError, ParseAoCInputError};
+pub use self::source::{InputSource, Stdin, Text, Reader, StdinOr, stdin_or};
+pub use self::source::{INPUT_DIR_ENV, STDIN_ENV, set_input_dir, input_dir, input_path};
+pub use self::blocks::{FromLines, ParseBlockError};
+pub use self::blocks::{read_blocks, try_read_blocks, parse_blocks, parse_blocks_safe, try_parse_blocks};
+pub use self::pattern::{Pattern, Field, FromFields, parse_field};
+pub use self::grid::{GridLoader, LoadedGrid, read_grid, parse_grid};
+pub use self::tokens::{FromTokens, TokenStream, stream_tokens_split_str, stream_tokens_split_chars};
+pub use self::bytes::{InputBytes, read_bytes, try_read_bytes, ScanInt, ScanInts, scan_ints, parse_int};
+pub use self::manifest::{MANIFEST_FILE, VERIFY_INPUT_ENV, set_verify_input, verify_input_enabled};
+pub use self::manifest::{verify_file, write_manifest, CheckedReader};
+
+type Err<T> = <T as FromStr>::Err;
+type TryResult<T> = Result<T, InputError>;
+
+/// like read_lines, but return an error instead of panicking if the file can't
+/// be opened or a line can't be read. Line numbers in the errors start at 1
+pub fn try_read_lines(day_xx: impl InputSource)
+	-> TryResult<impl Iterator<Item = TryResult<String>>>
+{
+	let path = day_xx.name();
+	let reader = manifest::open_checked(day_xx)?;
+	Ok(reader.lines().enumerate().map(move |(i, l)| {
+		l.map_err(|e| InputError::from_io(&path, Some(i + 1), e))
+	}))
+}
+
+pub fn read_lines(day_xx: impl InputSource) -> impl Iterator<Item = String> {
+	try_read_lines(day_xx)
+		.unwrap_or_else(|e| panic!("{}", e))
+		.map(|l| l.unwrap_or_else(|e| panic!("{}", e)))
+}
+
+/// parse each line with FromStr, panicking on error with the file and line number
+pub fn parse_lines<T>(day_xx: impl InputSource) -> impl Iterator<Item = T>
+where
+	T: FromStr,
+	Err<T>: fmt::Debug
+{
+	let path = day_xx.name();
+	read_lines(day_xx).enumerate().map(move |(i, l)| {
+		l.parse().unwrap_or_else(|e| panic!("{}:{}: {:?}", path.display(), i + 1, e))
+	})
+}
+
+/// like parse_lines, but return an error instead of panicking. Parse errors
+/// are reported as InputError::Parse, with the file and line number
+pub fn try_parse_lines<T>(day_xx: impl InputSource)
+	-> TryResult<impl Iterator<Item = TryResult<T>>>
+where
+	T: FromStr,
+	Err<T>: fmt::Debug
+{
+	let path = day_xx.name();
+	Ok(try_read_lines(day_xx)?.enumerate().map(move |(i, l)| {
+		l?.parse().map_err(|e| InputError::from_parse(&path, i + 1, e))
+	}))
+}
+
+/// parse each line with FromStr, returning the parse errors. The errors carry
+/// the file, line and text of the line, to be displayed like a diagnostic
+pub fn parse_lines_safe<T>(day_xx: impl InputSource)
+	-> impl Iterator<Item = Result<T, ParseAoCInputError<T>>>
+where
+	T: FromStr,
+	Err<T>: Into<ParseAoCInputError<T>>
+{
+	let path = day_xx.name();
+	read_lines(day_xx).enumerate().map(move |(i, l)| locate_parse(&l, &path, i + 1))
+}
+
+/// like parse_lines_safe, but return an error instead of panicking on I/O errors.
+/// Parse errors are returned in the inner Result
+pub fn try_parse_lines_safe<T>(day_xx: impl InputSource)
+	-> TryResult<impl Iterator<Item = TryResult<Result<T, ParseAoCInputError<T>>>>>
+where
+	T: FromStr,
+	Err<T>: Into<ParseAoCInputError<T>>
+{
+	let path = day_xx.name();
+	Ok(try_read_lines(day_xx)?.enumerate().map(move |(i, l)| {
+		l.map(|l| locate_parse(&l, &path, i + 1))
+	}))
+}
+
+fn locate_parse<T>(line: &str, path: &Path, line_num: usize) -> Result<T, ParseAoCInputError<T>>
+where
+	T: FromStr,
+	Err<T>: Into<ParseAoCInputError<T>>
+{
+	line.parse().map_err(|e: Err<T>| {
+		let err = e.into().with_location(path, line_num, line);
+		match err.span() {
+			Some(_) => err,
+			None => err.with_span(1..line.len() + 1),  // point to the whole line
+		}
+	})
+}
+
+pub fn read_tokens_split_str<T>(day_xx: impl InputSource, delim: &str) -> impl Iterator<Item = Vec<T>>
+where
+	T: FromStr,
+	Err<T>: fmt::Debug
+{
+	let delim = delim.to_string();
+	read_lines(day_xx).map(move |line| {
+		line.split(&delim)
+			.map(|token| token.parse::<T>().unwrap())
+			.collect()
+	})
+}
+
+/// like read_tokens_split_str, but return an error instead of panicking
+pub fn try_read_tokens_split_str<T>(day_xx: impl InputSource, delim: &str)
+	-> TryResult<impl Iterator<Item = TryResult<Vec<T>>>>
+where
+	T: FromStr,
+	Err<T>: fmt::Debug
+{
+	let delim = delim.to_string();
+	let path = day_xx.name();
+	Ok(try_read_lines(day_xx)?.enumerate().map(move |(i, l)| {
+		let line = l?;
+		line.split(&delim)
+			.map(|token| token.parse::<T>().map_err(|e| InputError::from_parse(&path, i + 1, e)))
+			.collect()
+	}))
+}
+
+pub fn read_tokens_safe_split_str<T>(day_xx: impl InputSource, delim: &str)
+	-> impl Iterator<Item = Result<Vec<T>, Err<T>>>
+where
+	T: FromStr
+{
+	let delim = delim.to_string();
+	read_lines(day_xx).map(move |line| {
+		line.split(&delim)
+			.map(|token| token.parse::<T>())
+			.collect()
+	})
+}
+
+/// like read_tokens_safe_split_str, but return an error instead of panicking on I/O errors
+pub fn try_read_tokens_safe_split_str<T>(day_xx: impl InputSource, delim: &str)
+	-> TryResult<impl Iterator<Item = TryResult<Result<Vec<T>, Err<T>>>>>
+where
+	T: FromStr
+{
+	let delim = delim.to_string();
+	Ok(try_read_lines(day_xx)?.map(move |line| {
+		line.map(|line| {
+			line.split(&delim)
+				.map(|token| token.parse::<T>())
+				.collect()
+		})
+	}))
+}
+
+pub fn read_tokens_split_chars<T>(day_xx: impl InputSource, delim: &[char]) -> impl Iterator<Item = Vec<T>> 
+where
+	T: FromStr,
+	Err<T>: fmt::Debug
+{
+	let delim = delim.to_vec();
+	read_lines(day_xx).map(move |line| {
+		line.split(delim.as_slice())
+			.map(|token| token.parse::<T>().unwrap())
+			.collect()
+	})
+}
+
+/// like read_tokens_split_chars, but return an error instead of panicking
+pub fn try_read_tokens_split_chars<T>(day_xx: impl InputSource, delim: &[char])
+	-> TryResult<impl Iterator<Item = TryResult<Vec<T>>>>
+where
+	T: FromStr,
+	Err<T>: fmt::Debug
+{
+	let delim = delim.to_vec();
+	let path = day_xx.name();
+	Ok(try_read_lines(day_xx)?.enumerate().map(move |(i, l)| {
+		let line = l?;
+		line.split(delim.as_slice())
+			.map(|token| token.parse::<T>().map_err(|e| InputError::from_parse(&path, i + 1, e)))
+			.collect()
+	}))
+}
+
+pub fn read_tokens_safe_split_chars<T>(day_xx: impl InputSource, delim: &[char])
+	-> impl Iterator<Item = Result<Vec<T>, Err<T>>>
+where
+	T: FromStr
+{
+	let delim = delim.to_vec();
+	read_lines(day_xx).map(move |line| {
+		line.split(delim.as_slice())
+			.map(|token| token.parse::<T>())
+			.collect()
+	})
+}
+
+/// like read_tokens_safe_split_chars, but return an error instead of panicking on I/O errors
+pub fn try_read_tokens_safe_split_chars<T>(day_xx: impl InputSource, delim: &[char])
+	-> TryResult<impl Iterator<Item = TryResult<Result<Vec<T>, Err<T>>>>>
+where
+	T: FromStr
+{
+	let delim = delim.to_vec();
+	Ok(try_read_lines(day_xx)?.map(move |line| {
+		line.map(|line| {
+			line.split(delim.as_slice())
+				.map(|token| token.parse::<T>())
+				.collect()
+		})
+	}))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::error::Error;
+
+	#[test]
+	fn test_try_read_lines_not_found() {
+		match try_read_lines("day00-missing") {
+			Err(InputError::NotFound { path }) => assert_eq!(path, input_path("day00-missing")),
+			_ => panic!("expected InputError::NotFound"),
+		}
+	}
+
+	#[test]
+	fn test_explicit_path() {
+		let by_name = read_lines("day01").collect::<Vec<_>>();
+		let by_path = read_lines(Path::new("input/day01.txt")).collect::<Vec<_>>();
+		assert_eq!(by_name, by_path);
+	}
+
+	#[test]
+	fn test_text_and_reader() {
+		let tokens = read_tokens_split_str::<u32>(Text("1,2\n3,4"), ",").collect::<Vec<_>>();
+		assert_eq!(tokens, vec![vec![1, 2], vec![3, 4]]);
+		let nums = parse_lines::<u32>(Reader("5\n6\n".as_bytes())).collect::<Vec<_>>();
+		assert_eq!(nums, vec![5, 6]);
+	}
+
+	#[test]
+	fn test_try_parse_lines_error_line() {
+		let err = try_parse_lines::<u32>(Text("1\n2\nthree\n4")).unwrap()
+			.find_map(Result::err)
+			.unwrap();
+		assert!(matches!(err, InputError::Parse { line: 3, .. }));
+	}
+
+	#[test]
+	fn test_blocks() {
+		let sums = parse_blocks::<Vec<u32>>(Text("1\n2\n\n3\n\n\n4\n5"))
+			.map(|block| block.iter().sum())
+			.collect::<Vec<u32>>();
+		assert_eq!(sums, vec![3, 3, 9]);
+
+		let err = parse_blocks_safe::<Vec<u32>>(Text("1\n\n2\nx"))
+			.find_map(Result::err)
+			.unwrap();
+		assert_eq!((err.block, err.line), (2, 3));
+	}
+
+	#[test]
+	fn test_pattern() {
+		let pattern = Pattern::new("Sensor at x={}, y={}: closest beacon is at x={}, y={}");
+		let line = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15";
+		let fields: (i32, i32, i32, i32) = pattern.parse::<(), _>(line).unwrap();
+		assert_eq!(fields, (2, 18, -2, 15));
+
+		let err = pattern.parse::<(), (i32, i32, i32, i32)>("Sensor at x=2, z=18").unwrap_err();
+		assert_eq!(err.column(), Some(16));
+		let err = pattern.parse::<(), (i32, i32, i32, i32)>("Sensor at x=2, y=1a: closest beacon is at x=-2, y=15").unwrap_err();
+		assert_eq!(err.column(), Some(18));
+	}
+
+	#[test]
+	fn test_parse_lines_safe_diagnostic() {
+		let err = parse_lines_safe::<u32>(Text("1\n2\n3x")).find_map(Result::err).unwrap();
+		assert_eq!((err.line(), err.span()), (Some(3), Some(1..3)));
+		assert!(err.source().is_some());
+		assert!(err.to_string().ends_with("\n  |\n3 | 3x\n  | ^^"));
+	}
+
+	#[test]
+	fn test_grid_loader() {
+		let loaded = GridLoader::new()
+			.markers(b"SE")
+			.padding(b' ')
+			.load(Text("S.#\n.E\n\nnot grid"), |b| b);
+		assert_eq!(loaded.grid, vec![b"S.#".to_vec(), b".E ".to_vec()]);
+		assert_eq!(loaded.marker(b'E'), Some((1, 1).into()));
+
+		let err = GridLoader::new().try_load(Text("..\n.x"), |b| match b {
+			b'.' => Ok(0),
+			b => Err(b as char),
+		});
+		assert!(matches!(err, Err(InputError::Parse { line: 2, .. })));
+	}
+
+	#[test]
+	fn test_stream_tokens() {
+		let ranges: Vec<(u32, u32, u32, u32)> = stream_tokens_split_chars(Text("2-4,6-8\n2-3,4-5"), &['-', ','])
+			.unwrapped()
+			.collect();
+		assert_eq!(ranges, vec![(2, 4, 6, 8), (2, 3, 4, 5)]);
+
+		let points: Vec<[i32; 3]> = stream_tokens_split_str(Text("1  2 3\n-1 0  4"), " ")
+			.skip_empty()
+			.unwrapped()
+			.collect();
+		assert_eq!(points, vec![[1, 2, 3], [-1, 0, 4]]);
+
+		let mut lines = stream_tokens_split_str::<(u8, u8), _>(Text("1,2\n3,x\n4"), ",");
+		assert!(lines.next().unwrap().is_ok());
+		let err = lines.next().unwrap().unwrap_err();
+		assert_eq!((err.line(), err.column()), (Some(2), Some(3)));
+		assert!(lines.next().unwrap().is_err());
+		assert!(lines.next().is_none());
+	}
+
+	#[test]
+	fn test_read_bytes() {
+		let bytes = read_bytes(Path::new("input/day01.txt"));
+		assert_eq!(bytes.as_str(), std::fs::read_to_string("input/day01.txt").unwrap());
+		assert_eq!(read_bytes(Text("abc\n")).as_bytes(), b"abc\n");
+
+		let err = try_read_bytes(Reader(&b"ok\n\xff"[..])).unwrap().try_as_str().unwrap_err();
+		assert!(matches!(err, InputError::Decode { line: 2, .. }));
+	}
+
+	#[test]
+	fn test_scan_ints() {
+		let nums: Vec<i32> = scan_ints(b"1\n-3\n  20,x=-7;y 0").collect();
+		assert_eq!(nums, vec![1, -3, 20, -7, 0]);
+		let nums: Vec<u8> = scan_ints(b"2-4,6-8").collect();
+		assert_eq!(nums, vec![2, 4, 6, 8]);
+
+		assert_eq!(parse_int::<i64>(b"-42"), Some(-42));
+		assert_eq!(parse_int::<i8>(b"-128"), Some(-128));
+		assert_eq!(parse_int::<u8>(b"256"), None);
+		assert_eq!(parse_int::<u32>(b"-1"), None);
+		assert_eq!(parse_int::<u32>(b""), None);
+	}
+
+	#[test]
+	fn test_manifest() {
+		let dir = std::env::temp_dir().join(format!("aoc-manifest-{}", std::process::id()));
+		std::fs::create_dir_all(&dir).unwrap();
+		std::fs::write(dir.join("day01.txt"), "1\n2\n3\n").unwrap();
+		std::fs::write(dir.join("day02.txt"), "A Y").unwrap();
+		std::fs::write(dir.join("notes.md"), "not an input").unwrap();
+
+		assert_eq!(write_manifest(&dir).unwrap(), 2);
+		assert!(verify_file(dir.join("day01.txt")).is_ok());
+		assert!(verify_file(dir.join("day02.txt")).is_ok());
+
+		std::fs::write(dir.join("day01.txt"), "1\n2\n").unwrap();
+		let err = verify_file(dir.join("day01.txt")).unwrap_err();
+		assert!(matches!(err, InputError::Manifest { .. }));
+		assert!(err.to_string().contains("expected 3 lines, found 2"));
+
+		std::fs::write(dir.join("day02.txt"), "A Y ").unwrap();
+		assert!(err_contains(verify_file(dir.join("day02.txt")), "SHA-256"));
+		assert!(err_contains(verify_file(dir.join("notes.md")), "not listed"));
+
+		std::fs::remove_dir_all(&dir).unwrap();
+	}
+
+	fn err_contains(result: Result<(), InputError>, msg: &str) -> bool {
+		matches!(result, Err(e) if e.to_string().contains(msg))
+	}
+}
@@ -0,0 +1,145 @@
+use super::ParseAoCInputError;
+use std::str::FromStr;
+use std::any::type_name;
+use std::fmt;
+
+/// template to extract the fields of a line, like
+/// `Pattern::new("move {} from {} to {}")`.
+///
+/// Each `{}` is a field and the text between them must match literally. A field
+/// ends where the text that follows it is found for first time, except the last
+/// one, that ends where the trailing text of the template is found at the end of
+/// the line. Because of that, fields must be separated by some text.
+///
+/// Columns in the errors start at 1.
+#[derive(Clone, Copy, Debug)]
+pub struct Pattern<'a> {
+	template: &'a str,
+}
+
+/// a field extracted with a Pattern
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Field<'s> {
+	pub text: &'s str,
+	pub column: usize,
+}
+
+impl<'a> Pattern<'a> {
+	pub const fn new(template: &'a str) -> Self {
+		Pattern { template }
+	}
+
+	/// number of fields of the template
+	pub fn fields_count(&self) -> usize {
+		self.template.matches("{}").count()
+	}
+
+	/// split the line in the fields of the template, or return an error
+	/// pointing to the column where the line doesn't match the template
+	pub fn fields<'s, T>(&self, s: &'s str) -> Result<Vec<Field<'s>>, ParseAoCInputError<T>> {
+		let mut literals = self.template.split("{}").peekable();
+		let mut fields = Vec::new();
+
+		let prefix = literals.next().unwrap();
+		if !s.starts_with(prefix) {
+			let column = 1 + common_prefix_len(s, prefix);
+			return Err(mismatch(s, prefix, column));
+		}
+		let mut pos = prefix.len();
+
+		while let Some(literal) = literals.next() {
+			let is_last = literals.peek().is_none();
+			let rest = &s[pos..];
+
+			let field_len = match (literal.is_empty(), is_last) {
+				(true, true) => Some(rest.len()),
+				(false, true) => match rest.ends_with(literal) {
+					true => Some(rest.len() - literal.len()),
+					false => None,
+				},
+				(_, false) => rest.find(literal).filter(|_| !literal.is_empty()),
+			};
+
+			match field_len {
+				Some(len) => {
+					fields.push(Field { text: &rest[..len], column: pos + 1 });
+					pos += len + literal.len();
+				},
+				None => return Err(mismatch(s, literal, pos + 1 + partial_match_len(rest, literal))),
+			}
+		}
+
+		Ok(fields)
+	}
+
+	/// extract the fields of the line and parse them to the types of F, i.e.
+	/// `let (x, y): (i32, i32) = Pattern::new("x={}, y={}").parse(line)?;`
+	pub fn parse<T, F: FromFields>(&self, s: &str) -> Result<F, ParseAoCInputError<T>> {
+		let fields = self.fields(s)?;
+		if fields.len() != F::COUNT {
+			let msg = format!("Pattern '{}' has {} fields, but {} were requested",
+			                  self.template, fields.len(), F::COUNT);
+			return Err(ParseAoCInputError::new_custom(&msg));
+		}
+		F::from_fields(&fields)
+	}
+}
+
+fn common_prefix_len(s1: &str, s2: &str) -> usize {
+	s1.bytes().zip(s2.bytes()).take_while(|(c1, c2)| c1 == c2).count()
+}
+
+/// when the literal is not found, the offset in s where it stops matching,
+/// according to the longest prefix of the literal that is found in s
+fn partial_match_len(s: &str, literal: &str) -> usize {
+	(1..literal.len()).rev()
+		.filter(|&len| literal.is_char_boundary(len))
+		.find_map(|len| s.find(&literal[..len]).map(|pos| pos + len))
+		.unwrap_or(0)
+}
+
+fn mismatch<T>(s: &str, expected: &str, column: usize) -> ParseAoCInputError<T> {
+	let msg = format!("Line '{}' doesn't match, expected '{}'", s, expected);
+	ParseAoCInputError::new_custom(&msg).at_column(column)
+}
+
+/// parse a single field, reporting its column on error
+pub fn parse_field<T, U>(field: &Field) -> Result<U, ParseAoCInputError<T>>
+where
+	U: FromStr,
+	U::Err: fmt::Debug
+{
+	field.text.parse().map_err(|err| {
+		let msg = format!("Can't parse field '{}' to type '{}': {:?}", field.text, type_name::<U>(), err);
+		ParseAoCInputError::new_custom(&msg).with_span(field.column..field.column + field.text.len())
+	})
+}
+
+/// types that can be built from the fields extracted with a Pattern
+pub trait FromFields: Sized {
+	const COUNT: usize;
+	fn from_fields<T>(fields: &[Field]) -> Result<Self, ParseAoCInputError<T>>;
+}
+
+macro_rules! impl_from_fields {
+	($count:expr; $($ty:ident: $idx:tt),+) => {
+		impl<$($ty),+> FromFields for ($($ty,)+)
+		where
+			$($ty: FromStr, $ty::Err: fmt::Debug),+
+		{
+			const COUNT: usize = $count;
+			fn from_fields<T>(fields: &[Field]) -> Result<Self, ParseAoCInputError<T>> {
+				Ok(($(parse_field(&fields[$idx])?,)+))
+			}
+		}
+	};
+}
+
+impl_from_fields!(1; A: 0);
+impl_from_fields!(2; A: 0, B: 1);
+impl_from_fields!(3; A: 0, B: 1, C: 2);
+impl_from_fields!(4; A: 0, B: 1, C: 2, D: 3);
+impl_from_fields!(5; A: 0, B: 1, C: 2, D: 3, E: 4);
+impl_from_fields!(6; A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
+impl_from_fields!(7; A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6);
+impl_from_fields!(8; A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7);
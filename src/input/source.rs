@@ -0,0 +1,223 @@
+use std::env;
+use std::ffi::OsString;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, StdinLock};
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+/// environment variable that can be used to set the input directory
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+/// environment variable that makes stdin_or read stdin, if set to anything but
+/// "" or "0"
+pub const STDIN_ENV: &str = "AOC_STDIN";
+
+static INPUT_DIR: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// set the directory where the input files are searched, overriding the
+/// environment variable AOC_INPUT_DIR and the default "input"
+pub fn set_input_dir(dir: impl Into<PathBuf>) {
+	*INPUT_DIR.write().unwrap() = Some(dir.into());
+}
+
+/// directory where the input files are searched. In order of precedence:
+/// the one set with set_input_dir, the env variable AOC_INPUT_DIR or "input"
+pub fn input_dir() -> PathBuf {
+	if let Some(dir) = INPUT_DIR.read().unwrap().as_ref() {
+		return dir.clone();
+	}
+	match env::var_os(INPUT_DIR_ENV) {
+		Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+		_ => PathBuf::from("input"),
+	}
+}
+
+/// path of the input file of a day, i.e. "input/day01.txt" for "day01"
+pub fn input_path(day_xx: &str) -> PathBuf {
+	input_dir().join(format!("{}.txt", day_xx))
+}
+
+/// what can be passed to the functions of this module to select where to read from:
+/// - a &str or String with the day name, like "day01", that is searched in input_dir()
+/// - a &Path or PathBuf, that is used as is
+/// - Stdin, Text("...") or Reader(some_bufread)
+/// - stdin_or("day01"), that reads stdin if asked with a `-` argument, or the day's file if not
+pub trait InputSource {
+	type Reader: BufRead;
+
+	/// name of the source, used in error messages
+	fn name(&self) -> PathBuf;
+
+	/// open the source for reading
+	fn open(self) -> io::Result<Self::Reader>;
+
+	/// path of the file, if the source is a file, so it can be read at once
+	fn file(&self) -> Option<PathBuf> {
+		None
+	}
+}
+
+/// InputSource for types that name a file, with $to_path giving its path
+macro_rules! impl_file_source {
+	($to_path:path: $($ty:ty),+) => {$(
+		impl InputSource for $ty {
+			type Reader = BufReader<File>;
+
+			fn name(&self) -> PathBuf {
+				$to_path(self)
+			}
+
+			fn file(&self) -> Option<PathBuf> {
+				Some(self.name())
+			}
+
+			fn open(self) -> io::Result<Self::Reader> {
+				File::open(self.name()).map(BufReader::new)
+			}
+		}
+	)+};
+}
+
+impl_file_source!(input_path: &str, &String);
+impl_file_source!(Path::to_path_buf: &Path, PathBuf, &PathBuf);
+
+/// read the input from the standard input
+pub struct Stdin;
+
+impl InputSource for Stdin {
+	type Reader = StdinLock<'static>;
+
+	fn name(&self) -> PathBuf {
+		PathBuf::from("<stdin>")
+	}
+
+	fn open(self) -> io::Result<Self::Reader> {
+		Ok(io::stdin().lock())
+	}
+}
+
+/// read the input from a string, useful to embed the examples in the tests
+pub struct Text<'a>(pub &'a str);
+
+impl<'a> InputSource for Text<'a> {
+	type Reader = &'a [u8];
+
+	fn name(&self) -> PathBuf {
+		PathBuf::from("<text>")
+	}
+
+	fn open(self) -> io::Result<Self::Reader> {
+		Ok(self.0.as_bytes())
+	}
+}
+
+/// read the input from any type implementing BufRead
+pub struct Reader<R: BufRead>(pub R);
+
+impl<R: BufRead> InputSource for Reader<R> {
+	type Reader = R;
+
+	fn name(&self) -> PathBuf {
+		PathBuf::from("<reader>")
+	}
+
+	fn open(self) -> io::Result<Self::Reader> {
+		Ok(self.0)
+	}
+}
+
+/// source returned by stdin_or
+pub enum StdinOr<'a> {
+	Stdin(StdinLock<'static>),
+	Day(&'a str),
+}
+
+/// read from stdin if it's asked explicitly, with a `-` argument (i.e.
+/// `dayXX - < file`) or the env variable AOC_STDIN, or from the day's input file
+/// otherwise. The state of stdin is not checked, as a pipe that is open but never
+/// written, like under CI or an IDE, would block forever
+pub fn stdin_or(day_xx: &str) -> StdinOr<'_> {
+	match stdin_requested(env::args().skip(1), env::var_os(STDIN_ENV)) {
+		true => StdinOr::Stdin(io::stdin().lock()),
+		false => StdinOr::Day(day_xx),
+	}
+}
+
+fn stdin_requested(mut args: impl Iterator<Item = String>, env_value: Option<OsString>) -> bool {
+	args.any(|arg| arg == "-") || env_value.is_some_and(|v| !v.is_empty() && v != "0")
+}
+
+impl<'a> InputSource for StdinOr<'a> {
+	type Reader = Box<dyn BufRead + 'a>;
+
+	fn name(&self) -> PathBuf {
+		match self {
+			StdinOr::Stdin(_) => Stdin.name(),
+			StdinOr::Day(day_xx) => day_xx.name(),
+		}
+	}
+
+	fn file(&self) -> Option<PathBuf> {
+		match self {
+			StdinOr::Stdin(_) => None,
+			StdinOr::Day(day_xx) => day_xx.file(),
+		}
+	}
+
+	fn open(self) -> io::Result<Self::Reader> {
+		match self {
+			StdinOr::Stdin(lock) => Ok(Box::new(lock)),
+			StdinOr::Day(day_xx) => Ok(Box::new(day_xx.open()?)),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::process::{Command, Stdio};
+	use std::time::{Duration, Instant};
+
+	const CHILD_ENV: &str = "AOC_TEST_STDIN_CHILD";
+
+	#[test]
+	fn test_stdin_requested() {
+		let args = |args: &[&str]| args.iter().map(|a| a.to_string()).collect::<Vec<_>>().into_iter();
+		assert!(!stdin_requested(args(&[]), None));
+		assert!(stdin_requested(args(&["-"]), None));
+		assert!(!stdin_requested(args(&["--verbose"]), Some("0".into())));
+		assert!(stdin_requested(args(&[]), Some("1".into())));
+	}
+
+	/// runs this test again in a child process, with stdin an open pipe that is
+	/// never written, and checks that stdin_or doesn't wait for it
+	#[test]
+	fn test_stdin_or_silent_pipe() {
+		if env::var_os(CHILD_ENV).is_some() {
+			assert!(matches!(stdin_or("day01"), StdinOr::Day("day01")));
+			return;
+		}
+
+		let mut child = Command::new(env::current_exe().unwrap())
+			.args(["--exact", "input::source::tests::test_stdin_or_silent_pipe", "--test-threads=1"])
+			.env(CHILD_ENV, "1")
+			.env_remove(STDIN_ENV)
+			.stdin(Stdio::piped())
+			.stdout(Stdio::null())
+			.spawn()
+			.unwrap();
+
+		let start = Instant::now();
+		let status = loop {
+			match child.try_wait().unwrap() {
+				Some(status) => break status,
+				None if start.elapsed() > Duration::from_secs(10) => {
+					child.kill().unwrap();
+					panic!("stdin_or blocked on an open pipe");
+				},
+				None => std::thread::sleep(Duration::from_millis(20)),
+			}
+		};
+		assert!(status.success());
+	}
+}
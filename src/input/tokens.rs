@@ -0,0 +1,174 @@
+use super::{InputSource, InputError, ParseAoCInputError, Field, parse_field};
+use super::manifest::{open_checked, CheckedReader};
+use std::io::BufRead;
+use std::marker::PhantomData;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::fmt;
+
+/// types that can be built from a fixed number of tokens of a line, like
+/// tuples `(u32, u32)` or arrays `[i32; 3]`
+pub trait FromTokens: Sized {
+	fn from_tokens<'a>(tokens: impl Iterator<Item = Field<'a>>) -> Result<Self, ParseAoCInputError<Self>>;
+}
+
+fn missing_tokens<T>(expected: usize, found: usize) -> ParseAoCInputError<T> {
+	let msg = format!("Expected {} tokens, found {}", expected, found);
+	ParseAoCInputError::new_custom(&msg)
+}
+
+fn check_no_more_tokens<'a, T>(mut tokens: impl Iterator<Item = Field<'a>>, expected: usize)
+	-> Result<(), ParseAoCInputError<T>>
+{
+	match tokens.next() {
+		Some(token) => {
+			let msg = format!("Expected {} tokens, found more", expected);
+			Err(ParseAoCInputError::new_custom(&msg).at_column(token.column))
+		},
+		None => Ok(()),
+	}
+}
+
+macro_rules! impl_from_tokens {
+	($count:expr; $($ty:ident: $idx:tt),+) => {
+		impl<$($ty),+> FromTokens for ($($ty,)+)
+		where
+			$($ty: FromStr, $ty::Err: fmt::Debug),+
+		{
+			fn from_tokens<'a>(mut tokens: impl Iterator<Item = Field<'a>>)
+				-> Result<Self, ParseAoCInputError<Self>>
+			{
+				let value = ($({
+					let token = tokens.next().ok_or_else(|| missing_tokens($count, $idx))?;
+					parse_field(&token)?
+				},)+);
+				check_no_more_tokens(tokens, $count)?;
+				Ok(value)
+			}
+		}
+	};
+}
+
+impl_from_tokens!(1; A: 0);
+impl_from_tokens!(2; A: 0, B: 1);
+impl_from_tokens!(3; A: 0, B: 1, C: 2);
+impl_from_tokens!(4; A: 0, B: 1, C: 2, D: 3);
+impl_from_tokens!(5; A: 0, B: 1, C: 2, D: 3, E: 4);
+impl_from_tokens!(6; A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
+impl_from_tokens!(7; A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6);
+impl_from_tokens!(8; A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7);
+
+impl<T, const N: usize> FromTokens for [T; N]
+where
+	T: FromStr,
+	T::Err: fmt::Debug
+{
+	fn from_tokens<'a>(mut tokens: impl Iterator<Item = Field<'a>>) -> Result<Self, ParseAoCInputError<Self>> {
+		let mut values: [Option<T>; N] = [(); N].map(|_| None);
+		for (i, value) in values.iter_mut().enumerate() {
+			let token = tokens.next().ok_or_else(|| missing_tokens(N, i))?;
+			*value = Some(parse_field(&token)?);
+		}
+		check_no_more_tokens(tokens, N)?;
+		Ok(values.map(Option::unwrap))
+	}
+}
+
+enum Delim {
+	Str(String),
+	Chars(Vec<char>),
+}
+
+/// Iterator over the lines of the input, parsing each one from its tokens.
+///
+/// Lines are read into a single reused buffer and the tokens are parsed
+/// directly from it, so there are no allocations per line or per token
+/// (unless the requested types allocate, like String). Parse errors carry
+/// the location and text of the line. I/O errors panic, like in read_lines.
+pub struct TokenStream<F, R> {
+	reader: R,
+	path: PathBuf,
+	buf: String,
+	line: usize,
+	delim: Delim,
+	skip_empty: bool,
+	data_type: PhantomData<F>,
+}
+
+impl<F: FromTokens, R: BufRead> TokenStream<F, R> {
+	fn new(reader: R, path: PathBuf, delim: Delim) -> Self {
+		TokenStream { reader, path, buf: String::new(), line: 0, delim, skip_empty: false, data_type: PhantomData }
+	}
+
+	/// ignore the empty tokens, like the ones between consecutive delimiters
+	pub fn skip_empty(mut self) -> Self {
+		self.skip_empty = true;
+		self
+	}
+
+	/// panic on parse errors instead of returning them
+	pub fn unwrapped(self) -> impl Iterator<Item = F> {
+		self.map(|tokens| tokens.unwrap_or_else(|e| panic!("{}", e)))
+	}
+
+	fn parse_line(&self, line: &str) -> Result<F, ParseAoCInputError<F>> {
+		let to_field = |token| token_field(line, token);
+		let skip_empty = self.skip_empty;
+		let keep = move |token: &&str| !skip_empty || !token.is_empty();
+
+		match &self.delim {
+			Delim::Str(delim) => F::from_tokens(line.split(delim.as_str()).filter(keep).map(to_field)),
+			Delim::Chars(delims) => F::from_tokens(line.split(delims.as_slice()).filter(keep).map(to_field)),
+		}
+	}
+}
+
+impl<F: FromTokens, R: BufRead> Iterator for TokenStream<F, R> {
+	type Item = Result<F, ParseAoCInputError<F>>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.buf.clear();
+		self.line += 1;
+		match self.reader.read_line(&mut self.buf) {
+			Ok(0) => return None,
+			Ok(_) => (),
+			Err(e) => panic!("{}", InputError::from_io(&self.path, Some(self.line), e)),
+		}
+
+		let line = self.buf.trim_end_matches(['\n', '\r']);
+		Some(self.parse_line(line).map_err(|e| e.with_location(&self.path, self.line, line)))
+	}
+}
+
+/// the token as a Field, with its column in the line
+fn token_field<'a>(line: &str, token: &'a str) -> Field<'a> {
+	Field { text: token, column: token.as_ptr() as usize - line.as_ptr() as usize + 1 }
+}
+
+fn open<S: InputSource>(day_xx: S) -> (CheckedReader<S::Reader>, PathBuf) {
+	let path = day_xx.name();
+	let reader = open_checked(day_xx).unwrap_or_else(|e| panic!("{}", e));
+	(reader, path)
+}
+
+/// like read_tokens_safe_split_str, but parsing each line to a fixed number of
+/// tokens, like `(u32, u32)` or `[i32; 3]`, without allocating for each line
+pub fn stream_tokens_split_str<F, S>(day_xx: S, delim: &str) -> TokenStream<F, CheckedReader<S::Reader>>
+where
+	F: FromTokens,
+	S: InputSource
+{
+	let (reader, path) = open(day_xx);
+	TokenStream::new(reader, path, Delim::Str(delim.to_string()))
+}
+
+/// like read_tokens_safe_split_chars, but parsing each line to a fixed number of
+/// tokens, like `(u32, u32)` or `[i32; 3]`, without allocating for each line
+pub fn stream_tokens_split_chars<F, S>(day_xx: S, delim: &[char]) -> TokenStream<F, CheckedReader<S::Reader>>
+where
+	F: FromTokens,
+	S: InputSource
+{
+	let (reader, path) = open(day_xx);
+	TokenStream::new(reader, path, Delim::Chars(delim.to_vec()))
+}
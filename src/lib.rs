@@ -1,3 +1,6 @@
 pub mod input;
 #[allow(non_snake_case)]
 pub mod space_2D;
+#[allow(non_snake_case)]
+pub mod space_ND;
+pub mod search;
@@ -0,0 +1,221 @@
+//! Generic graph searches over any hashable node type, like grid points or
+//! puzzle states. The graph is defined by a closure returning the neighbours
+//! of a node, so it doesn't need to be built beforehand:
+//!
+//! ```
+//! use adventofcode2022::search;
+//! use adventofcode2022::space_2D::{ArrayGrid, Grid, Point};
+//!
+//! let grid = ArrayGrid::from_rows(vec![b"..#".to_vec(), b"...".to_vec()]);
+//! let start = Point::<usize>::from((0, 0));
+//! let end = Point::from((0, 2));
+//! let found = search::bfs(
+//!     [start],
+//!     |&p| grid.adjacents(p).inside().map(|(_, p)| p).filter(|&p| grid[p] == b'.'),
+//!     |&p| p == Point::from((1, 2)),
+//! );
+//! assert_eq!(found.goal_cost(), Some(3));
+//! assert_eq!(found.cost(&end), None);
+//! ```
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// the nodes reached by a search, with their cost from the nearest start and
+/// their parent, to reconstruct the paths
+#[derive(Clone, Debug)]
+pub struct SearchResult<N> {
+	costs: HashMap<N, usize>,
+	parents: HashMap<N, N>,
+	goal: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash> SearchResult<N> {
+	fn new() -> Self {
+		SearchResult { costs: HashMap::new(), parents: HashMap::new(), goal: None }
+	}
+
+	/// the first node found that satisfies the goal predicate
+	pub fn goal(&self) -> Option<&N> {
+		self.goal.as_ref()
+	}
+
+	/// cost from the nearest start to the goal
+	pub fn goal_cost(&self) -> Option<usize> {
+		self.cost(self.goal.as_ref()?)
+	}
+
+	/// cost from the nearest start to node, or None if it was not reached
+	pub fn cost(&self, node: &N) -> Option<usize> {
+		self.costs.get(node).copied()
+	}
+
+	/// the nodes from a start to node, both included, or None if it was not reached
+	pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+		self.costs.get(node)?;
+		let mut path = vec![node.clone()];
+		while let Some(parent) = self.parents.get(path.last().unwrap()) {
+			path.push(parent.clone());
+		}
+		path.reverse();
+		Some(path)
+	}
+
+	/// the path from a start to the goal
+	pub fn goal_path(&self) -> Option<Vec<N>> {
+		self.path_to(self.goal.as_ref()?)
+	}
+
+	/// iterate over the reached nodes and their costs. If the search stopped at
+	/// the goal, nodes further than it can be missing
+	pub fn reached(&self) -> impl Iterator<Item = (&N, usize)> + '_ {
+		self.costs.iter().map(|(node, cost)| (node, *cost))
+	}
+}
+
+/// breadth-first search from all the starts at once, where each step costs 1.
+/// It stops at the first node that satisfies goal, so use `|_| false` to reach
+/// all the nodes
+pub fn bfs<N, I>(
+	starts: impl IntoIterator<Item = N>,
+	mut neighbours: impl FnMut(&N) -> I,
+	mut goal: impl FnMut(&N) -> bool,
+) -> SearchResult<N>
+where
+	N: Clone + Eq + Hash,
+	I: IntoIterator<Item = N>
+{
+	let mut result = SearchResult::new();
+	let mut queue = VecDeque::new();
+	for start in starts {
+		if !result.costs.contains_key(&start) {
+			result.costs.insert(start.clone(), 0);
+			queue.push_back(start);
+		}
+	}
+
+	while let Some(node) = queue.pop_front() {
+		if goal(&node) {
+			result.goal = Some(node);
+			break;
+		}
+
+		let cost = result.costs[&node] + 1;
+		for next in neighbours(&node) {
+			if !result.costs.contains_key(&next) {
+				result.costs.insert(next.clone(), cost);
+				result.parents.insert(next.clone(), node.clone());
+				queue.push_back(next);
+			}
+		}
+	}
+
+	result
+}
+
+/// Dijkstra's search from all the starts at once, where neighbours returns the
+/// next nodes along with the cost of the step to them. It stops at the first
+/// node that satisfies goal, so use `|_| false` to reach all the nodes
+pub fn dijkstra<N, I>(
+	starts: impl IntoIterator<Item = N>,
+	neighbours: impl FnMut(&N) -> I,
+	goal: impl FnMut(&N) -> bool,
+) -> SearchResult<N>
+where
+	N: Clone + Eq + Hash,
+	I: IntoIterator<Item = (N, usize)>
+{
+	astar(starts, neighbours, |_| 0, goal)
+}
+
+/// A* search, like dijkstra but expanding first the nodes with the lower cost
+/// plus heuristic, that is the estimated cost to the goal. The heuristic must
+/// never overestimate it, like the manhattan distance in a grid
+pub fn astar<N, I>(
+	starts: impl IntoIterator<Item = N>,
+	mut neighbours: impl FnMut(&N) -> I,
+	mut heuristic: impl FnMut(&N) -> usize,
+	mut goal: impl FnMut(&N) -> bool,
+) -> SearchResult<N>
+where
+	N: Clone + Eq + Hash,
+	I: IntoIterator<Item = (N, usize)>
+{
+	let mut result = SearchResult::new();
+	// the heap has indexes of nodes, so N doesn't need to implement Ord
+	let mut nodes = Vec::new();
+	let mut queue = BinaryHeap::new();
+	for start in starts {
+		if !result.costs.contains_key(&start) {
+			result.costs.insert(start.clone(), 0);
+			queue.push(Reverse((heuristic(&start), 0, nodes.len())));
+			nodes.push(start);
+		}
+	}
+
+	while let Some(Reverse((_, cost, idx))) = queue.pop() {
+		let node = nodes[idx].clone();
+		if result.costs[&node] < cost {
+			continue;  // already reached with a lower cost
+		}
+		if goal(&node) {
+			result.goal = Some(node);
+			break;
+		}
+
+		for (next, step_cost) in neighbours(&node) {
+			let next_cost = cost + step_cost;
+			if result.costs.get(&next).is_none_or(|&prev| next_cost < prev) {
+				result.costs.insert(next.clone(), next_cost);
+				result.parents.insert(next.clone(), node.clone());
+				queue.push(Reverse((next_cost + heuristic(&next), next_cost, nodes.len())));
+				nodes.push(next);
+			}
+		}
+	}
+
+	result
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// a small weighted graph: 0 -> 1 -> 3 is cheaper than 0 -> 2 -> 3
+	fn edges(node: &u32) -> Vec<(u32, usize)> {
+		match node {
+			0 => vec![(1, 1), (2, 1)],
+			1 => vec![(3, 1)],
+			2 => vec![(3, 5)],
+			_ => vec![],
+		}
+	}
+
+	#[test]
+	fn test_bfs() {
+		let result = bfs([0], |n| edges(n).into_iter().map(|(n, _)| n), |_| false);
+		assert_eq!(result.reached().count(), 4);
+		assert_eq!(result.cost(&3), Some(2));
+		assert_eq!(result.goal(), None);
+
+		let result = bfs([2, 1], |n| edges(n).into_iter().map(|(n, _)| n), |n| *n == 3);
+		assert_eq!(result.goal_cost(), Some(1));
+		assert_eq!(result.goal_path(), Some(vec![2, 3]));
+		assert_eq!(result.cost(&0), None);
+	}
+
+	#[test]
+	fn test_dijkstra_astar() {
+		let result = dijkstra([0], edges, |n| *n == 3);
+		assert_eq!(result.goal_cost(), Some(2));
+		assert_eq!(result.goal_path(), Some(vec![0, 1, 3]));
+		assert_eq!(result.path_to(&4), None);
+
+		let result = astar([0], edges, |n| 3 - (*n).min(3) as usize / 2, |n| *n == 3);
+		assert_eq!(result.goal_path(), Some(vec![0, 1, 3]));
+
+		let result = dijkstra([0], edges, |_| false);
+		assert_eq!(result.reached().map(|(_, cost)| cost).max(), Some(2));
+	}
+}
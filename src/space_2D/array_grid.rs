@@ -0,0 +1,290 @@
+use super::{Point, Grid, GridMut, Rect, VecGrid};
+use std::ops;
+
+/// 2D Grid stored in a single Vec, row after row. Can be indexed with
+/// Point<usize>, Point<isize>, or with the row number to get a row slice, so
+/// `grid[y][x]` works like with a VecGrid
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ArrayGrid<T> {
+	height: usize,
+	width: usize,
+	data: Vec<T>,
+}
+
+impl<T> ArrayGrid<T> {
+	/// new grid with all the cells set to value
+	pub fn new(height: usize, width: usize, value: T) -> Self
+	where
+		T: Clone
+	{
+		ArrayGrid { height, width, data: vec![value; height * width] }
+	}
+
+	/// new grid with the value of each cell returned by f
+	pub fn from_fn(height: usize, width: usize, mut f: impl FnMut(Point) -> T) -> Self {
+		let data = (0..height * width).map(|i| f(Point::from((i / width, i % width)))).collect();
+		ArrayGrid { height, width, data }
+	}
+
+	/// new grid from the cells, row after row. Panics if the length is wrong
+	pub fn from_vec(height: usize, width: usize, data: Vec<T>) -> Self {
+		assert_eq!(data.len(), height * width, "Wrong length for a {}x{} grid", height, width);
+		ArrayGrid { height, width, data }
+	}
+
+	/// new grid from the rows of a VecGrid. Panics if they have different lengths
+	pub fn from_rows(rows: VecGrid<T>) -> Self {
+		let (height, width) = (rows.len(), rows.first().map_or(0, Vec::len));
+		let mut data = Vec::with_capacity(height * width);
+		for (y, row) in rows.into_iter().enumerate() {
+			assert_eq!(row.len(), width, "Row {} has length {}, expected {}", y, row.len(), width);
+			data.extend(row);
+		}
+		ArrayGrid { height, width, data }
+	}
+
+	pub fn height(&self) -> usize {
+		self.height
+	}
+
+	pub fn width(&self) -> usize {
+		self.width
+	}
+
+	/// all the cells, row after row
+	pub fn as_slice(&self) -> &[T] {
+		&self.data
+	}
+
+	pub fn as_mut_slice(&mut self) -> &mut [T] {
+		&mut self.data
+	}
+
+	pub fn into_vec(self) -> Vec<T> {
+		self.data
+	}
+
+	/// position in the flat buffer of a point, that must be in the grid
+	pub fn index_of(&self, point: Point) -> usize {
+		point.y * self.width + point.x
+	}
+
+	/// point of a position of the flat buffer
+	pub fn point_of(&self, index: usize) -> Point {
+		Point::from((index / self.width, index % self.width))
+	}
+
+	pub fn row(&self, y: usize) -> &[T] {
+		&self.data[y * self.width..(y + 1) * self.width]
+	}
+
+	pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+		&mut self.data[y * self.width..(y + 1) * self.width]
+	}
+
+	pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + '_ {
+		(0..self.height).map(|y| self.row(y))
+	}
+
+	/// cells of the column x, from top to bottom. It iterates the buffer with
+	/// a stride of width, so it can be reversed to go from bottom to top
+	pub fn col(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + '_ {
+		assert!(x < self.width, "Column {} out of bounds, width is {}", x, self.width);
+		self.data[x..].iter().step_by(self.width)
+	}
+
+	pub fn col_mut(&mut self, x: usize) -> impl DoubleEndedIterator<Item = &mut T> + '_ {
+		assert!(x < self.width, "Column {} out of bounds, width is {}", x, self.width);
+		self.data[x..].iter_mut().step_by(self.width)
+	}
+
+	pub fn cols(&self) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T>> + '_ {
+		(0..self.width).map(|x| self.col(x))
+	}
+
+	/// iterate the buffer from a point, advancing step cells each time, i.e.
+	/// step = width + 1 goes through the diagonal. It wraps at the end of rows
+	pub fn iter_stride(&self, start: Point, step: usize) -> impl DoubleEndedIterator<Item = &T> + '_ {
+		self.data[self.index_of(start)..].iter().step_by(step)
+	}
+}
+
+impl<T> Grid<usize> for ArrayGrid<T> {
+	type Item = T;
+
+	fn contains_point(&self, point: Point<usize>) -> bool {
+		point.y < self.height && point.x < self.width
+	}
+
+	fn get_point(&self, point: Point<usize>) -> Option<&Self::Item> {
+		match self.contains_point(point) {
+			true => Some(&self.data[self.index_of(point)]),
+			false => None
+		}
+	}
+
+	fn iter_grid(&self) -> Box<dyn Iterator<Item = (Point<usize>, &Self::Item)> + '_> {
+		let width = self.width;
+		Box::new(self.data.iter().enumerate().map(move |(i, v)| (Point::from((i / width, i % width)), v)))
+	}
+
+	fn bounds(&self) -> Option<Rect<usize>> {
+		match self.data.is_empty() {
+			true => None,
+			false => Some(Rect::new(Point::default(), Point::from((self.height - 1, self.width - 1)))),
+		}
+	}
+}
+
+impl<T> GridMut<usize> for ArrayGrid<T> {
+	fn get_point_mut(&mut self, point: Point<usize>) -> Option<&mut Self::Item> {
+		match self.contains_point(point) {
+			true => {
+				let idx = self.index_of(point);
+				Some(&mut self.data[idx])
+			},
+			false => None
+		}
+	}
+
+	fn iter_grid_mut(&mut self) -> Box<dyn Iterator<Item = (Point<usize>, &mut Self::Item)> + '_> {
+		let width = self.width;
+		Box::new(self.data.iter_mut().enumerate().map(move |(i, v)| (Point::from((i / width, i % width)), v)))
+	}
+
+	fn iter_rect_mut(&mut self, rect: Rect<usize>) -> Box<dyn Iterator<Item = (Point<usize>, &mut Self::Item)> + '_> {
+		Box::new(self.data.chunks_mut(self.width.max(1)).enumerate().skip(rect.min.y).take(rect.height())
+			.flat_map(move |(y, row)| row.iter_mut().enumerate().skip(rect.min.x).take(rect.width())
+				.map(move |(x, v)| (Point::from((y, x)), v))))
+	}
+}
+
+impl<T> Grid<isize> for ArrayGrid<T> {
+	type Item = T;
+
+	fn contains_point(&self, point: Point<isize>) -> bool {
+		(0..self.height as isize).contains(&point.y) && (0..self.width as isize).contains(&point.x)
+	}
+
+	fn get_point(&self, point: Point<isize>) -> Option<&Self::Item> {
+		self.get_point(Point::<usize>::try_from(point).ok()?)
+	}
+
+	fn iter_grid(&self) -> Box<dyn Iterator<Item = (Point<isize>, &Self::Item)> + '_> {
+		Box::new(Grid::<usize>::iter_grid(self).map(|(p, v)| (Point::from((p.y as isize, p.x as isize)), v)))
+	}
+
+	fn bounds(&self) -> Option<Rect<isize>> {
+		let rect = Grid::<usize>::bounds(self)?;
+		Some(Rect::new(rect.min.try_into().ok()?, rect.max.try_into().ok()?))
+	}
+}
+
+impl<T> GridMut<isize> for ArrayGrid<T> {
+	fn get_point_mut(&mut self, point: Point<isize>) -> Option<&mut Self::Item> {
+		self.get_point_mut(Point::<usize>::try_from(point).ok()?)
+	}
+
+	fn iter_grid_mut(&mut self) -> Box<dyn Iterator<Item = (Point<isize>, &mut Self::Item)> + '_> {
+		Box::new(GridMut::<usize>::iter_grid_mut(self).map(|(p, v)| (Point::from((p.y as isize, p.x as isize)), v)))
+	}
+
+	fn iter_rect_mut(&mut self, rect: Rect<isize>) -> Box<dyn Iterator<Item = (Point<isize>, &mut Self::Item)> + '_> {
+		match rect.non_negative() {
+			Some(rect) => Box::new(GridMut::<usize>::iter_rect_mut(self, rect)
+				.map(|(p, v)| (Point::from((p.y as isize, p.x as isize)), v))),
+			None => Box::new(std::iter::empty()),
+		}
+	}
+}
+
+impl<T> ops::Index<Point<usize>> for ArrayGrid<T> {
+	type Output = T;
+	fn index(&self, index: Point<usize>) -> &Self::Output {
+		assert!(index.x < self.width, "Point {} out of bounds, width is {}", index, self.width);
+		&self.data[self.index_of(index)]
+	}
+}
+
+impl<T> ops::IndexMut<Point<usize>> for ArrayGrid<T> {
+	fn index_mut(&mut self, index: Point<usize>) -> &mut Self::Output {
+		assert!(index.x < self.width, "Point {} out of bounds, width is {}", index, self.width);
+		let idx = self.index_of(index);
+		&mut self.data[idx]
+	}
+}
+
+/// Warn: like with VecGrid, it will panic if indexed with negative values
+impl<T> ops::Index<Point<isize>> for ArrayGrid<T> {
+	type Output = T;
+	fn index(&self, index: Point<isize>) -> &Self::Output {
+		&self[Point::<usize>::try_from(index).unwrap()]
+	}
+}
+
+impl<T> ops::IndexMut<Point<isize>> for ArrayGrid<T> {
+	fn index_mut(&mut self, index: Point<isize>) -> &mut Self::Output {
+		&mut self[Point::<usize>::try_from(index).unwrap()]
+	}
+}
+
+/// get the row y, so it can be indexed like `grid[y][x]`
+impl<T> ops::Index<usize> for ArrayGrid<T> {
+	type Output = [T];
+	fn index(&self, y: usize) -> &Self::Output {
+		self.row(y)
+	}
+}
+
+impl<T> ops::IndexMut<usize> for ArrayGrid<T> {
+	fn index_mut(&mut self, y: usize) -> &mut Self::Output {
+		self.row_mut(y)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn grid_3x4() -> ArrayGrid<usize> {
+		ArrayGrid::from_fn(3, 4, |p| p.y * 10 + p.x)
+	}
+
+	#[test]
+	fn test_rows_cols() {
+		let grid = grid_3x4();
+		assert_eq!((grid.height(), grid.width()), (3, 4));
+		assert_eq!(grid.row(1), &[10, 11, 12, 13]);
+		assert_eq!(grid[2][3], 23);
+		assert_eq!(grid.col(2).copied().collect::<Vec<_>>(), vec![2, 12, 22]);
+		assert_eq!(grid.col(3).rev().copied().collect::<Vec<_>>(), vec![23, 13, 3]);
+		assert_eq!(grid.iter_stride(Point::from((0, 0)), 5).copied().collect::<Vec<_>>(), vec![0, 11, 22]);
+		assert_eq!(grid, ArrayGrid::from_rows(grid.rows().map(<[_]>::to_vec).collect()));
+	}
+
+	#[test]
+	fn test_grid_trait() {
+		let mut grid = grid_3x4();
+		assert!(grid.contains_point(Point::<usize>::from((2, 3))));
+		assert!(!grid.contains_point(Point::<usize>::from((0, 4))));
+		assert!(!grid.contains_point(Point::<isize>::from((-1, 0))));
+		assert_eq!(grid.get_point(Point::<isize>::from((1, 2))), Some(&12));
+		assert_eq!(grid.get_point(Point::<usize>::from((3, 0))), None);
+
+		*grid.get_point_mut(Point::<usize>::from((1, 1))).unwrap() = 99;
+		assert_eq!(grid[Point::<isize>::from((1, 1))], 99);
+		assert_eq!(grid.adjacents_4(Point::<usize>::from((0, 0))), vec![(0, 1).into(), (1, 0).into()]);
+
+		let (p, v) = Grid::<usize>::iter_grid(&grid).nth(6).unwrap();
+		assert_eq!((p, *v), (Point::from((1, 2)), 12));
+		assert_eq!(Grid::<isize>::bounds(&grid), Some(Rect::new(Point::from((0, 0)), Point::from((2, 3)))));
+	}
+
+	#[test]
+	fn test_empty() {
+		let grid: ArrayGrid<u8> = ArrayGrid::from_rows(Vec::new());
+		assert!(!grid.contains_point(Point::<usize>::from((0, 0))));
+		assert_eq!(Grid::<usize>::iter_grid(&grid).count(), 0);
+		assert_eq!(Grid::<usize>::bounds(&grid), None);
+	}
+}
@@ -0,0 +1,275 @@
+use super::{Point, Grid, Rect, VecGrid};
+use std::ops;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+static TRUE: bool = true;
+static FALSE: bool = false;
+
+/// 2D Grid of booleans packed in bits, with each row stored in u64 words, the
+/// cell x being the bit x of the row.
+///
+/// The rows of grids up to 64 wide fit in one word, and can be read and written
+/// as integers with row and set_row, to check collisions or hash rows with word
+/// operations. As bits can't be borrowed mutably, the cells are written with
+/// set, and it implements Grid but not GridMut:
+///
+/// ```compile_fail
+/// use adventofcode2022::space_2D::{BitGrid, GridMut, Point};
+/// let mut grid = BitGrid::new(2, 2);
+/// GridMut::<usize>::get_point_mut(&mut grid, Point::from((0, 0)));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+	height: usize,
+	width: usize,
+	words_per_row: usize,
+	data: Vec<u64>,
+}
+
+impl BitGrid {
+	/// new grid with all the cells set to false
+	pub fn new(height: usize, width: usize) -> Self {
+		let words_per_row = width.div_ceil(WORD_BITS);
+		BitGrid { height, width, words_per_row, data: vec![0; height * words_per_row] }
+	}
+
+	/// new grid from the rows of a VecGrid. Panics if they have different lengths
+	pub fn from_rows(rows: VecGrid<bool>) -> Self {
+		let mut grid = BitGrid::new(rows.len(), rows.first().map_or(0, Vec::len));
+		for (y, row) in rows.iter().enumerate() {
+			assert_eq!(row.len(), grid.width, "Row {} has length {}, expected {}", y, row.len(), grid.width);
+			for (x, value) in row.iter().enumerate() {
+				grid.set(Point::from((y, x)), *value);
+			}
+		}
+		grid
+	}
+
+	pub fn height(&self) -> usize {
+		self.height
+	}
+
+	pub fn width(&self) -> usize {
+		self.width
+	}
+
+	/// add rows set to false, or remove them from the bottom, to get height rows
+	pub fn resize(&mut self, height: usize) {
+		self.height = height;
+		self.data.resize(height * self.words_per_row, 0);
+	}
+
+	/// value of a cell. Panics if the point is out of the grid
+	pub fn get(&self, point: Point) -> bool {
+		let (word, bit) = self.bit_of(point);
+		self.data[word] >> bit & 1 == 1
+	}
+
+	/// set the value of a cell. Panics if the point is out of the grid
+	pub fn set(&mut self, point: Point, value: bool) {
+		let (word, bit) = self.bit_of(point);
+		match value {
+			true => self.data[word] |= 1 << bit,
+			false => self.data[word] &= !(1 << bit),
+		}
+	}
+
+	/// number of cells set to true
+	pub fn count_ones(&self) -> usize {
+		self.data.iter().map(|word| word.count_ones() as usize).sum()
+	}
+
+	/// number of cells of the row y set to true
+	pub fn row_count_ones(&self, y: usize) -> usize {
+		self.row_words(y).iter().map(|word| word.count_ones() as usize).sum()
+	}
+
+	/// the words of the row y, with the cell x in the bit x % 64 of the word x / 64
+	pub fn row_words(&self, y: usize) -> &[u64] {
+		&self.data[y * self.words_per_row..(y + 1) * self.words_per_row]
+	}
+
+	pub fn row_words_mut(&mut self, y: usize) -> &mut [u64] {
+		&mut self.data[y * self.words_per_row..(y + 1) * self.words_per_row]
+	}
+
+	/// the row y as an integer. Panics if the grid is wider than 64
+	pub fn row(&self, y: usize) -> u64 {
+		self.assert_one_word();
+		self.data[y]
+	}
+
+	/// set the row y from an integer. Panics if the grid is wider than 64, or
+	/// bits has bits set out of the grid
+	pub fn set_row(&mut self, y: usize, bits: u64) {
+		assert!(self.fits(bits), "Bits {:#b} out of a row of width {}", bits, self.width);
+		self.data[y] = bits;
+	}
+
+	/// set to true the cells of the row y that are set in bits
+	pub fn or_row(&mut self, y: usize, bits: u64) {
+		self.set_row(y, self.row(y) | bits);
+	}
+
+	/// if any cell of the row y is set both in the row and in bits
+	pub fn overlaps(&self, y: usize, bits: u64) -> bool {
+		self.row(y) & bits != 0
+	}
+
+	/// the bits of a full row. Panics if the grid is wider than 64
+	pub fn row_mask(&self) -> u64 {
+		self.assert_one_word();
+		match self.width {
+			WORD_BITS => u64::MAX,
+			width => (1 << width) - 1,
+		}
+	}
+
+	/// if bits has no bits set out of a row, i.e. after shifting a shape
+	pub fn fits(&self, bits: u64) -> bool {
+		bits & !self.row_mask() == 0
+	}
+
+	/// move all the cells delta columns to the right, or to the left if negative.
+	/// The cells that leave the grid are lost. Panics if the grid is wider than 64
+	pub fn shift_x(&mut self, delta: isize) {
+		let mask = self.row_mask();
+		for word in self.data.iter_mut() {
+			*word = match delta >= 0 {
+				true => word.checked_shl(delta as u32).unwrap_or(0) & mask,
+				false => word.checked_shr(delta.unsigned_abs() as u32).unwrap_or(0),
+			};
+		}
+	}
+
+	fn assert_one_word(&self) {
+		assert!(self.width <= WORD_BITS, "Row of width {} doesn't fit in a u64", self.width);
+	}
+
+	fn bit_of(&self, point: Point) -> (usize, usize) {
+		assert!(self.contains_point(point), "Point {} out of a {}x{} grid", point, self.height, self.width);
+		(point.y * self.words_per_row + point.x / WORD_BITS, point.x % WORD_BITS)
+	}
+
+	fn cell_ref(&self, point: Point) -> &'static bool {
+		match self.get(point) {
+			true => &TRUE,
+			false => &FALSE,
+		}
+	}
+}
+
+impl Grid<usize> for BitGrid {
+	type Item = bool;
+
+	fn contains_point(&self, point: Point<usize>) -> bool {
+		point.y < self.height && point.x < self.width
+	}
+
+	fn get_point(&self, point: Point<usize>) -> Option<&Self::Item> {
+		match self.contains_point(point) {
+			true => Some(self.cell_ref(point)),
+			false => None
+		}
+	}
+
+	fn iter_grid(&self) -> Box<dyn Iterator<Item = (Point<usize>, &Self::Item)> + '_> {
+		let width = self.width;
+		Box::new((0..self.height * width).map(move |i| {
+			let point = Point::from((i / width, i % width));
+			(point, self.cell_ref(point))
+		}))
+	}
+
+	fn bounds(&self) -> Option<Rect<usize>> {
+		match self.height == 0 || self.width == 0 {
+			true => None,
+			false => Some(Rect::new(Point::default(), Point::from((self.height - 1, self.width - 1)))),
+		}
+	}
+}
+
+impl Grid<isize> for BitGrid {
+	type Item = bool;
+
+	fn contains_point(&self, point: Point<isize>) -> bool {
+		Point::<usize>::try_from(point).is_ok_and(|p| self.contains_point(p))
+	}
+
+	fn get_point(&self, point: Point<isize>) -> Option<&Self::Item> {
+		self.get_point(Point::<usize>::try_from(point).ok()?)
+	}
+
+	fn iter_grid(&self) -> Box<dyn Iterator<Item = (Point<isize>, &Self::Item)> + '_> {
+		Box::new(Grid::<usize>::iter_grid(self).map(|(p, v)| (Point::from((p.y as isize, p.x as isize)), v)))
+	}
+
+	fn bounds(&self) -> Option<Rect<isize>> {
+		let rect = Grid::<usize>::bounds(self)?;
+		Some(Rect::new(rect.min.try_into().ok()?, rect.max.try_into().ok()?))
+	}
+}
+
+impl ops::Index<Point<usize>> for BitGrid {
+	type Output = bool;
+	fn index(&self, index: Point<usize>) -> &Self::Output {
+		self.cell_ref(index)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_bits() {
+		let mut grid = BitGrid::new(2, 70);
+		grid.set(Point::from((1, 65)), true);
+		grid.set(Point::from((0, 3)), true);
+		assert!(grid.get(Point::from((1, 65))));
+		assert!(!grid.get(Point::from((1, 64))));
+		assert_eq!(grid.row_words(1), &[0, 0b10]);
+		assert_eq!((grid.count_ones(), grid.row_count_ones(0)), (2, 1));
+
+		grid.set(Point::from((0, 3)), false);
+		assert_eq!(grid.count_ones(), 1);
+		assert_eq!(Grid::<usize>::iter_grid(&grid).filter(|(_, v)| **v).map(|(p, _)| p).collect::<Vec<_>>(), vec![(1, 65).into()]);
+		assert_eq!(grid.get_point(Point::<isize>::from((-1, 0))), None);
+	}
+
+	#[test]
+	fn test_rows() {
+		let mut grid = BitGrid::from_rows(vec![vec![true, false, false], vec![false, true, true]]);
+		assert_eq!((grid.row(0), grid.row(1)), (0b001, 0b110));
+		assert_eq!(grid.row_mask(), 0b111);
+		assert!(grid.fits(0b011 << 1));
+		assert!(!grid.fits(0b011 << 2));
+		assert!(grid.overlaps(1, 0b100));
+		assert!(!grid.overlaps(0, 0b110));
+
+		grid.or_row(0, 0b100);
+		assert_eq!(grid.row(0), 0b101);
+		grid.shift_x(1);
+		assert_eq!((grid.row(0), grid.row(1)), (0b010, 0b100));
+		grid.shift_x(-2);
+		assert_eq!((grid.row(0), grid.row(1)), (0b000, 0b001));
+
+		grid.resize(3);
+		assert_eq!(grid.height(), 3);
+		assert!(!grid[Point::from((2, 0))]);
+	}
+
+	/// generic code that only reads the grid works with a BitGrid
+	fn count_set<G: Grid<usize, Item = bool>>(grid: &G) -> usize {
+		grid.iter_grid().filter(|(_, v)| **v).count()
+	}
+
+	#[test]
+	fn test_generic_grid() {
+		let grid = BitGrid::from_rows(vec![vec![true, false], vec![true, true]]);
+		assert_eq!(count_set(&grid), 3);
+		let adjacents: Vec<_> = grid.adjacents(Point::<usize>::from((0, 1))).inside().map(|(_, p)| p).collect();
+		assert_eq!(adjacents, vec![(0, 0).into(), (1, 1).into()]);
+	}
+}
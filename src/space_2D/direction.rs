@@ -0,0 +1,181 @@
+use super::Point;
+use crate::input::ParseAoCInputError;
+use std::str::FromStr;
+use Direction::*;
+
+/// one of the 8 directions of a grid, with rows growing downwards, so North is
+/// (-1, 0) and East is (0, 1). The variants are in clockwise order
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+	North,
+	NorthEast,
+	East,
+	SouthEast,
+	South,
+	SouthWest,
+	West,
+	NorthWest,
+}
+
+impl Direction {
+	/// the 4 cardinal directions, clockwise from North
+	pub const ALL_4: [Direction; 4] = [North, East, South, West];
+
+	/// the 8 directions, clockwise from North
+	pub const ALL_8: [Direction; 8] = [North, NorthEast, East, SouthEast, South, SouthWest, West, NorthWest];
+
+	/// unit vector of the direction, to add it to a Point
+	pub const fn to_point(self) -> Point<isize> {
+		let (y, x) = match self {
+			North     => (-1, 0),
+			NorthEast => (-1, 1),
+			East      => (0, 1),
+			SouthEast => (1, 1),
+			South     => (1, 0),
+			SouthWest => (1, -1),
+			West      => (0, -1),
+			NorthWest => (-1, -1),
+		};
+		Point { y, x }
+	}
+
+	/// the direction of a unit vector, like (0, -1) or (1, 1)
+	pub fn from_point(point: Point<isize>) -> Option<Direction> {
+		Self::ALL_8.into_iter().find(|dir| dir.to_point() == point)
+	}
+
+	/// the direction for a letter or arrow: N/S/E/W, U/D/L/R or ^/v/>/<
+	pub fn from_char(ch: char) -> Option<Direction> {
+		match ch {
+			'N' | 'U' | '^' => Some(North),
+			'E' | 'R' | '>' => Some(East),
+			'S' | 'D' | 'v' => Some(South),
+			'W' | 'L' | '<' => Some(West),
+			_ => None,
+		}
+	}
+
+	fn rotate(self, eighths: usize) -> Direction {
+		Self::ALL_8[(self as usize + eighths) % 8]
+	}
+
+	/// turn 90º clockwise
+	pub fn turn_right(self) -> Direction {
+		self.rotate(2)
+	}
+
+	/// turn 90º counterclockwise
+	pub fn turn_left(self) -> Direction {
+		self.rotate(6)
+	}
+
+	/// turn 45º clockwise
+	pub fn turn_right_45(self) -> Direction {
+		self.rotate(1)
+	}
+
+	/// turn 45º counterclockwise
+	pub fn turn_left_45(self) -> Direction {
+		self.rotate(7)
+	}
+
+	pub fn reverse(self) -> Direction {
+		self.rotate(4)
+	}
+
+	pub fn is_diagonal(self) -> bool {
+		self as usize % 2 == 1
+	}
+
+	/// facing score as the number of clockwise 90º turns from East: East is 0,
+	/// South 1, West 2 and North 3. None for the diagonals
+	pub fn facing(self) -> Option<usize> {
+		match self.is_diagonal() {
+			true => None,
+			false => Some((self as usize + 6) % 8 / 2),
+		}
+	}
+}
+
+impl From<Direction> for Point<isize> {
+	fn from(dir: Direction) -> Self {
+		dir.to_point()
+	}
+}
+
+impl TryFrom<Point<isize>> for Direction {
+	type Error = ParseAoCInputError<Direction>;
+
+	fn try_from(point: Point<isize>) -> Result<Self, Self::Error> {
+		Direction::from_point(point)
+			.ok_or_else(|| ParseAoCInputError::new_custom(&format!("{} is not a unit vector", point)))
+	}
+}
+
+impl TryFrom<char> for Direction {
+	type Error = ParseAoCInputError<Direction>;
+
+	fn try_from(ch: char) -> Result<Self, Self::Error> {
+		Direction::from_char(ch).ok_or_else(|| ParseAoCInputError::new(&ch.to_string()))
+	}
+}
+
+/// to load grids of arrows with GridLoader::parse
+impl TryFrom<u8> for Direction {
+	type Error = ParseAoCInputError<Direction>;
+
+	fn try_from(b: u8) -> Result<Self, Self::Error> {
+		Direction::try_from(b as char)
+	}
+}
+
+/// parse a letter or arrow, like from_char, or the diagonals "NE", "SE", "SW" and "NW"
+impl FromStr for Direction {
+	type Err = ParseAoCInputError<Direction>;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let mut chars = s.chars();
+		match (chars.next(), chars.next(), chars.next()) {
+			(Some(ch), None, None) => Direction::try_from(ch),
+			(Some('N'), Some('E'), None) => Ok(NorthEast),
+			(Some('S'), Some('E'), None) => Ok(SouthEast),
+			(Some('S'), Some('W'), None) => Ok(SouthWest),
+			(Some('N'), Some('W'), None) => Ok(NorthWest),
+			_ => Err(ParseAoCInputError::new(s)),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_turns() {
+		assert_eq!(North.turn_right(), East);
+		assert_eq!(North.turn_left(), West);
+		assert_eq!(SouthWest.turn_right_45(), West);
+		assert_eq!(North.turn_left_45(), NorthWest);
+		assert_eq!(East.reverse(), West);
+		assert!(Direction::ALL_8.iter().all(|d| d.turn_left().turn_right() == *d));
+	}
+
+	#[test]
+	fn test_points() {
+		assert_eq!(South.to_point(), Point::from((1, 0)));
+		assert_eq!(Point::from(NorthWest), Point::from((-1, -1)));
+		assert!(Direction::ALL_8.iter().all(|d| Direction::from_point(d.to_point()) == Some(*d)));
+		assert!(Direction::try_from(Point::from((0, 2))).is_err());
+	}
+
+	#[test]
+	fn test_parse_and_facing() {
+		assert_eq!("L".parse::<Direction>().unwrap(), West);
+		assert_eq!("NE".parse::<Direction>().unwrap(), NorthEast);
+		assert_eq!(Direction::try_from(b'v').unwrap(), South);
+		assert!("X".parse::<Direction>().is_err());
+
+		let facings: Vec<_> = [East, South, West, North, NorthEast].iter().map(|d| d.facing()).collect();
+		assert_eq!(facings, vec![Some(0), Some(1), Some(2), Some(3), None]);
+	}
+}
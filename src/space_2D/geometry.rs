@@ -0,0 +1,154 @@
+use super::{Point, Coord};
+
+macro_rules! impl_distances {
+	($(($ty:ty, $uty:ty)),+) => {$(
+		impl Point<$ty> {
+			/// number of horizontal and vertical steps to reach other
+			pub fn manhattan_dist(&self, other: Point<$ty>) -> $uty {
+				self.y.abs_diff(other.y) + self.x.abs_diff(other.x)
+			}
+
+			/// number of steps to reach other, if diagonal steps are allowed
+			pub fn chebyshev_dist(&self, other: Point<$ty>) -> $uty {
+				self.y.abs_diff(other.y).max(self.x.abs_diff(other.x))
+			}
+
+			/// square of the euclidean distance, to compare distances without floats
+			pub fn squared_dist(&self, other: Point<$ty>) -> $uty {
+				let (dy, dx) = (self.y.abs_diff(other.y), self.x.abs_diff(other.x));
+				dy * dy + dx * dx
+			}
+
+			/// iterate over the points of the segment from self to end, both included.
+			/// None if the segment is not horizontal, vertical or diagonal (45º)
+			pub fn line_to(&self, end: Point<$ty>) -> Option<Line<$ty>> {
+				let (dy, dx) = (self.y.abs_diff(end.y), self.x.abs_diff(end.x));
+				match dy == 0 || dx == 0 || dy == dx {
+					true => Some(Line::new(*self, end)),
+					false => None,
+				}
+			}
+		}
+	)+};
+}
+
+impl_distances!(
+	(isize, usize), (i8, u8), (i16, u16), (i32, u32), (i64, u64), (i128, u128),
+	(usize, usize), (u8, u8), (u16, u16), (u32, u32), (u64, u64), (u128, u128)
+);
+
+/// rotations and reflections, with rows growing downwards like in Direction,
+/// so rotating East (0, 1) to the right gives South (1, 0)
+macro_rules! impl_rotations {
+	($($ty:ty),+) => {$(
+		impl Point<$ty> {
+			/// rotate 90º clockwise around the origin
+			pub fn rotate_right(&self) -> Point<$ty> {
+				Point { y: self.x, x: -self.y }
+			}
+
+			/// rotate 90º counterclockwise around the origin
+			pub fn rotate_left(&self) -> Point<$ty> {
+				Point { y: -self.x, x: self.y }
+			}
+
+			/// rotate 90º clockwise around pivot
+			pub fn rotate_right_around(&self, pivot: Point<$ty>) -> Point<$ty> {
+				(*self - pivot).rotate_right() + pivot
+			}
+
+			/// rotate 90º counterclockwise around pivot
+			pub fn rotate_left_around(&self, pivot: Point<$ty>) -> Point<$ty> {
+				(*self - pivot).rotate_left() + pivot
+			}
+
+			/// mirror across the vertical axis, negating x
+			pub fn reflect_x(&self) -> Point<$ty> {
+				Point { y: self.y, x: -self.x }
+			}
+
+			/// mirror across the horizontal axis, negating y
+			pub fn reflect_y(&self) -> Point<$ty> {
+				Point { y: -self.y, x: self.x }
+			}
+		}
+	)+};
+}
+
+impl_rotations!(isize, i8, i16, i32, i64, i128);
+
+impl<T: Coord> Point<T> {
+	/// mirror across the diagonal, swapping y and x
+	pub fn transpose(&self) -> Point<T> {
+		Point { y: self.x, x: self.y }
+	}
+}
+
+/// Iterator over the points of a horizontal, vertical or diagonal segment,
+/// returned by Point::line_to
+pub struct Line<T: Coord> {
+	next: Option<Point<T>>,
+	end: Point<T>,
+	step: Point<isize>,
+}
+
+impl<T: Coord> Line<T> {
+	pub(crate) fn new(start: Point<T>, end: Point<T>) -> Self {
+		let sign = |from: T, to: T| to.cmp(&from) as isize;
+		Line { next: Some(start), end, step: Point { y: sign(start.y, end.y), x: sign(start.x, end.x) } }
+	}
+}
+
+impl<T: Coord> Iterator for Line<T> {
+	type Item = Point<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let point = self.next?;
+		self.next = match point == self.end {
+			true => None,
+			false => point.offset(self.step),
+		};
+		Some(point)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_distances() {
+		let (a, b) = (Point::<isize>::from((-1, 2)), Point::from((3, -1)));
+		assert_eq!(a.manhattan_dist(b), 7);
+		assert_eq!(a.chebyshev_dist(b), 4);
+		assert_eq!(a.squared_dist(b), 25);
+		assert_eq!(Point::<usize>::from((5, 0)).manhattan_dist(Point::from((0, 5))), 10);
+	}
+
+	#[test]
+	fn test_rotations() {
+		let east = Point::<isize>::from((0, 1));
+		assert_eq!(east.rotate_right(), Point::from((1, 0)));
+		assert_eq!(east.rotate_left(), Point::from((-1, 0)));
+		assert_eq!(east.rotate_right().rotate_right().rotate_right(), east.rotate_left());
+
+		let (p, pivot) = (Point::<isize>::from((2, 5)), Point::from((2, 2)));
+		assert_eq!(p.rotate_right_around(pivot), Point::from((5, 2)));
+		assert_eq!(p.rotate_left_around(pivot), Point::from((-1, 2)));
+		assert_eq!(p.reflect_x(), Point::from((2, -5)));
+		assert_eq!(p.reflect_y(), Point::from((-2, 5)));
+		assert_eq!(p.transpose(), Point::from((5, 2)));
+	}
+
+	#[test]
+	fn test_line() {
+		let line: Vec<_> = Point::<usize>::from((2, 0)).line_to(Point::from((0, 2))).unwrap().collect();
+		assert_eq!(line, vec![(2, 0).into(), (1, 1).into(), (0, 2).into()]);
+
+		let start = Point::<i32>::from((4, 1));
+		assert_eq!(start.line_to(Point::from((4, -3))).unwrap().count(), 5);
+		assert_eq!(start.line_to(Point::from((7, 1))).unwrap().last(), Some(Point::from((7, 1))));
+		assert_eq!(start.line_to(start).unwrap().collect::<Vec<_>>(), vec![start]);
+		assert!(start.line_to(Point::from((5, 3))).is_none());
+	}
+}
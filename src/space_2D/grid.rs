@@ -1,4 +1,9 @@
 use super::point::{Point, Coord};
+use super::neighbours::{Neighbours, DirNeighbours, Ray, OFFSETS_4, OFFSETS_8, DIRECTIONS_4};
+use super::direction::Direction;
+use super::rect::{Rect, SubGrid};
+use super::render::Renderer;
+use super::view::{GridView, Transform};
 
 pub trait Grid<T: Coord> {
 	type Item;
@@ -9,15 +14,193 @@ pub trait Grid<T: Coord> {
 	/// get a immutable ref to the element in the point
 	fn get_point(&self, point: Point<T>) -> Option<&Self::Item>;
 
-	/// get a mutable ref to the element in the point
-	fn get_point_mut(&mut self, point: Point<T>) -> Option<&mut Self::Item>;
+	/// position and direction after a step from point in the direction dir, or
+	/// None if it leaves the grid. The neighbourhood helpers and ray are built on
+	/// it, so grids whose edges wrap around, like TopoGrid, only override this
+	fn step(&self, point: Point<T>, dir: Point<isize>) -> Option<(Point<T>, Point<isize>)> {
+		point.offset(dir).filter(|p| self.contains_point(*p)).map(|p| (p, dir))
+	}
 
-	/// get the 4 adjacent points (left, right, top, bottom) if they exist
-	fn adjacents_4(&self, point: Point<T>) -> Vec<Point<T>>;
+	/// get the 4 adjacent points (top, left, right, bottom) that are in the grid.
+	/// Prefer adjacents, that doesn't allocate
+	fn adjacents_4(&self, point: Point<T>) -> Vec<Point<T>> {
+		self.adjacents(point).inside().map(|(_, p)| p).collect()
+	}
 
 	/// iterate over the grid in an enumerate fashion
 	fn iter_grid(&self) -> Box<dyn Iterator<Item = (Point<T>, &Self::Item)> + '_>;
 
+	/// iterate over the points at the given offsets from point that are in the
+	/// grid, along with their offset, i.e. `grid.neighbours(p, &[NORTH_WEST, NORTH])`
+	fn neighbours<'a>(&'a self, point: Point<T>, offsets: &'a [Point<isize>]) -> Neighbours<'a, Self, T> {
+		Neighbours::new(self, point, offsets)
+	}
+
+	/// iterate over the 4 adjacent points (top, left, right, bottom) along with
+	/// their direction, or None if they are out of the grid
+	fn adjacents(&self, point: Point<T>) -> DirNeighbours<'_, Self, T> {
+		DirNeighbours::new(self, point, &DIRECTIONS_4)
+	}
+
+	/// iterate over the neighbours of point in the directions dirs, or None for
+	/// the ones out of the grid, i.e. `grid.neighbours_dir(p, &Direction::ALL_8)`
+	fn neighbours_dir<'a>(&'a self, point: Point<T>, dirs: &'a [Direction]) -> DirNeighbours<'a, Self, T> {
+		DirNeighbours::new(self, point, dirs)
+	}
+
+	/// iterate over the 4 adjacent points (left, right, top, bottom) that are in the grid
+	fn neighbours_4(&self, point: Point<T>) -> Neighbours<'_, Self, T> {
+		self.neighbours(point, &OFFSETS_4)
+	}
+
+	/// iterate over the 8 surrounding points, including diagonals, that are in the grid
+	fn neighbours_8(&self, point: Point<T>) -> Neighbours<'_, Self, T> {
+		self.neighbours(point, &OFFSETS_8)
+	}
+
+	/// iterate over the points from point (not included) in the direction dir,
+	/// until leaving the grid or getting back to point, i.e. around a Torus. It
+	/// never ends in grids without edges, like HashGrid
+	fn ray(&self, point: Point<T>, dir: Point<isize>) -> Ray<'_, Self, T> {
+		Ray::new(self, point, dir)
+	}
+
+	/// the smallest rect containing all the points of the grid, or None if it's
+	/// empty. For unbounded grids, like HashGrid, the rect of the cells set. The
+	/// default scans the whole grid, dense grids answer it from their size
+	fn bounds(&self) -> Option<Rect<T>> {
+		Rect::from_points(self.iter_grid().map(|(p, _)| p))
+	}
+
+	/// iterate over the points in rect that are in the grid, row after row. It
+	/// visits only the points of rect, not the whole grid
+	fn iter_rect(&self, rect: Rect<T>) -> Box<dyn Iterator<Item = (Point<T>, &Self::Item)> + '_> {
+		Box::new(rect.iter().filter_map(|p| Some((p, self.get_point(p)?))))
+	}
+
+	/// read-only view of the part of the grid in rect, that is also a Grid
+	fn sub_grid(&self, rect: Rect<T>) -> SubGrid<&Self, T> {
+		SubGrid::new(self, rect)
+	}
+
+	/// read-only rotated or flipped view of the grid, i.e.
+	/// `grid.view(Transform::Rotate90)`. It covers the rect of bounds
+	fn view(&self, transform: Transform) -> GridView<&Self, T> {
+		GridView::new(self, self.bounds(), transform)
+	}
+
+	/// read-only rotated or flipped view of the part of the grid in rect
+	fn view_rect(&self, rect: Rect<T>, transform: Transform) -> GridView<&Self, T> {
+		GridView::new(self, Some(rect), transform)
+	}
+
+	/// render the grid as text, drawing each cell with format, i.e. to print it
+	/// with `println!("{}", grid.render(|v| *v as char))`
+	fn render<F: Fn(&Self::Item) -> char>(&self, format: F) -> Renderer<'_, Self, T, F> {
+		Renderer::new(self, format)
+	}
+}
+
+/// Grid whose cells can be borrowed mutably. BitGrid doesn't implement it, as
+/// its cells are bits: they are written with BitGrid::set instead
+pub trait GridMut<T: Coord>: Grid<T> {
+	/// get a mutable ref to the element in the point
+	fn get_point_mut(&mut self, point: Point<T>) -> Option<&mut Self::Item>;
+
 	/// iterate over the grid in an enumerate fashion with mutable refs
 	fn iter_grid_mut(&mut self) -> Box<dyn Iterator<Item = (Point<T>, &mut Self::Item)> + '_>;
+
+	/// like iter_rect, but with mutable refs, in the order of iter_grid_mut. The
+	/// default walks the whole grid, the dense grids visit only the rect
+	fn iter_rect_mut(&mut self, rect: Rect<T>) -> Box<dyn Iterator<Item = (Point<T>, &mut Self::Item)> + '_> {
+		Box::new(self.iter_grid_mut().filter(move |(p, _)| rect.contains(*p)))
+	}
+
+	/// like sub_grid, but the view can modify the grid
+	fn sub_grid_mut(&mut self, rect: Rect<T>) -> SubGrid<&mut Self, T> {
+		SubGrid::new(self, rect)
+	}
+
+	/// like view, but the view can modify the grid
+	fn view_mut(&mut self, transform: Transform) -> GridView<&mut Self, T> {
+		let rect = self.bounds();
+		GridView::new(self, rect, transform)
+	}
+
+	/// like view_rect, but the view can modify the grid
+	fn view_rect_mut(&mut self, rect: Rect<T>, transform: Transform) -> GridView<&mut Self, T> {
+		GridView::new(self, Some(rect), transform)
+	}
+}
+
+/// a reference to a grid is a grid too, so the views can borrow the inner grid
+/// immutably or mutably
+impl<G: Grid<T> + ?Sized, T: Coord> Grid<T> for &G {
+	type Item = G::Item;
+
+	fn contains_point(&self, point: Point<T>) -> bool {
+		(**self).contains_point(point)
+	}
+
+	fn get_point(&self, point: Point<T>) -> Option<&Self::Item> {
+		(**self).get_point(point)
+	}
+
+	fn iter_grid(&self) -> Box<dyn Iterator<Item = (Point<T>, &Self::Item)> + '_> {
+		(**self).iter_grid()
+	}
+
+	fn step(&self, point: Point<T>, dir: Point<isize>) -> Option<(Point<T>, Point<isize>)> {
+		(**self).step(point, dir)
+	}
+
+	fn bounds(&self) -> Option<Rect<T>> {
+		(**self).bounds()
+	}
+
+	fn iter_rect(&self, rect: Rect<T>) -> Box<dyn Iterator<Item = (Point<T>, &Self::Item)> + '_> {
+		(**self).iter_rect(rect)
+	}
+}
+
+impl<G: Grid<T> + ?Sized, T: Coord> Grid<T> for &mut G {
+	type Item = G::Item;
+
+	fn contains_point(&self, point: Point<T>) -> bool {
+		(**self).contains_point(point)
+	}
+
+	fn get_point(&self, point: Point<T>) -> Option<&Self::Item> {
+		(**self).get_point(point)
+	}
+
+	fn iter_grid(&self) -> Box<dyn Iterator<Item = (Point<T>, &Self::Item)> + '_> {
+		(**self).iter_grid()
+	}
+
+	fn step(&self, point: Point<T>, dir: Point<isize>) -> Option<(Point<T>, Point<isize>)> {
+		(**self).step(point, dir)
+	}
+
+	fn bounds(&self) -> Option<Rect<T>> {
+		(**self).bounds()
+	}
+
+	fn iter_rect(&self, rect: Rect<T>) -> Box<dyn Iterator<Item = (Point<T>, &Self::Item)> + '_> {
+		(**self).iter_rect(rect)
+	}
+}
+
+impl<G: GridMut<T> + ?Sized, T: Coord> GridMut<T> for &mut G {
+	fn get_point_mut(&mut self, point: Point<T>) -> Option<&mut Self::Item> {
+		(**self).get_point_mut(point)
+	}
+
+	fn iter_grid_mut(&mut self) -> Box<dyn Iterator<Item = (Point<T>, &mut Self::Item)> + '_> {
+		(**self).iter_grid_mut()
+	}
+
+	fn iter_rect_mut(&mut self, rect: Rect<T>) -> Box<dyn Iterator<Item = (Point<T>, &mut Self::Item)> + '_> {
+		(**self).iter_rect_mut(rect)
+	}
 }
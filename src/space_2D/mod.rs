@@ -1,7 +1,29 @@
 mod point;
 mod grid;
+mod geometry;
+mod rect;
+mod render;
+mod view;
+mod neighbours;
+mod direction;
 mod vec_grid;
+mod array_grid;
+mod bit_grid;
+mod sparse_grid;
+mod topology;
 
-pub use self::point::Point;
-pub use self::grid::Grid;
+pub use self::point::{Point, Coord};
+pub use self::grid::{Grid, GridMut};
+pub use self::geometry::Line;
+pub use self::rect::{Rect, SubGrid};
+pub use self::render::Renderer;
+pub use self::view::{GridView, Transform};
+pub use self::direction::Direction;
+pub use self::neighbours::{Neighbours, DirNeighbours, Ray};
+pub use self::neighbours::{NORTH, SOUTH, WEST, EAST, NORTH_WEST, NORTH_EAST, SOUTH_WEST, SOUTH_EAST};
+pub use self::neighbours::{OFFSETS_4, OFFSETS_8, OFFSETS_DIAGONAL};
 pub use self::vec_grid::VecGrid;
+pub use self::array_grid::ArrayGrid;
+pub use self::bit_grid::BitGrid;
+pub use self::sparse_grid::{HashGrid, BTreeGrid};
+pub use self::topology::{Topology, Bounded, Torus, WrapNonBlank, Stitched, TopoGrid};
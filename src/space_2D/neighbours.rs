@@ -0,0 +1,189 @@
+use super::{Point, Grid, Direction};
+use super::point::Coord;
+use std::slice;
+
+pub const NORTH: Point<isize> = Direction::North.to_point();
+pub const SOUTH: Point<isize> = Direction::South.to_point();
+pub const WEST: Point<isize> = Direction::West.to_point();
+pub const EAST: Point<isize> = Direction::East.to_point();
+pub const NORTH_WEST: Point<isize> = Direction::NorthWest.to_point();
+pub const NORTH_EAST: Point<isize> = Direction::NorthEast.to_point();
+pub const SOUTH_WEST: Point<isize> = Direction::SouthWest.to_point();
+pub const SOUTH_EAST: Point<isize> = Direction::SouthEast.to_point();
+
+/// offsets of the 4 adjacent points, in the same order as adjacents_4
+pub const OFFSETS_4: [Point<isize>; 4] = [NORTH, WEST, EAST, SOUTH];
+
+/// directions of the 4 adjacent points, in the same order as OFFSETS_4
+pub(crate) const DIRECTIONS_4: [Direction; 4] = [Direction::North, Direction::West, Direction::East, Direction::South];
+
+/// offsets of the 4 diagonal points
+pub const OFFSETS_DIAGONAL: [Point<isize>; 4] = [NORTH_WEST, NORTH_EAST, SOUTH_WEST, SOUTH_EAST];
+
+/// offsets of the 8 surrounding points, row after row
+pub const OFFSETS_8: [Point<isize>; 8] = [
+	NORTH_WEST, NORTH, NORTH_EAST,
+	WEST, EAST,
+	SOUTH_WEST, SOUTH, SOUTH_EAST,
+];
+
+/// the point after a step in the direction dir, if it's possible and it doesn't
+/// get back to point, like in a row of a Torus with only 1 cell
+fn neighbour<G, T>(grid: &G, point: Point<T>, dir: Point<isize>) -> Option<Point<T>>
+where
+	G: Grid<T> + ?Sized,
+	T: Coord
+{
+	grid.step(point, dir).map(|(p, _)| p).filter(|p| *p != point)
+}
+
+/// Iterator over the neighbours of a point that are in the grid, returned by
+/// Grid::neighbours. Each item is the offset (direction) and the point
+pub struct Neighbours<'a, G: ?Sized, T: Coord> {
+	grid: &'a G,
+	point: Point<T>,
+	offsets: slice::Iter<'a, Point<isize>>,
+}
+
+impl<'a, G: ?Sized, T: Coord> Neighbours<'a, G, T> {
+	pub(crate) fn new(grid: &'a G, point: Point<T>, offsets: &'a [Point<isize>]) -> Self {
+		Neighbours { grid, point, offsets: offsets.iter() }
+	}
+}
+
+impl<'a, G, T> Iterator for Neighbours<'a, G, T>
+where
+	G: Grid<T> + ?Sized,
+	T: Coord
+{
+	type Item = (Point<isize>, Point<T>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.offsets.by_ref()
+			.find_map(|&offset| neighbour(self.grid, self.point, offset).map(|p| (offset, p)))
+	}
+}
+
+/// Iterator over the neighbours of a point in some directions, returned by
+/// Grid::adjacents and Grid::neighbours_dir. Each item is the direction and the
+/// neighbour, or None if it's out of the grid. Use inside to get only the
+/// neighbours in the grid, or outside to get the directions cut off by the edges
+pub struct DirNeighbours<'a, G: ?Sized, T: Coord> {
+	grid: &'a G,
+	point: Point<T>,
+	dirs: slice::Iter<'a, Direction>,
+}
+
+impl<'a, G, T> DirNeighbours<'a, G, T>
+where
+	G: Grid<T> + ?Sized,
+	T: Coord
+{
+	pub(crate) fn new(grid: &'a G, point: Point<T>, dirs: &'a [Direction]) -> Self {
+		DirNeighbours { grid, point, dirs: dirs.iter() }
+	}
+
+	/// the neighbours that are in the grid
+	pub fn inside(self) -> impl Iterator<Item = (Direction, Point<T>)> + 'a {
+		self.filter_map(|(dir, point)| Some((dir, point?)))
+	}
+
+	/// the directions whose neighbour is out of the grid
+	pub fn outside(self) -> impl Iterator<Item = Direction> + 'a {
+		self.filter(|(_, point)| point.is_none()).map(|(dir, _)| dir)
+	}
+}
+
+impl<'a, G, T> Iterator for DirNeighbours<'a, G, T>
+where
+	G: Grid<T> + ?Sized,
+	T: Coord
+{
+	type Item = (Direction, Option<Point<T>>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let dir = *self.dirs.next()?;
+		let point = neighbour(self.grid, self.point, dir.to_point());
+		Some((dir, point))
+	}
+}
+
+/// Iterator over the points from a point (not included) in a direction, until
+/// leaving the grid or getting back to the start. Returned by Grid::ray. The
+/// direction can't be (0, 0), and it can change when crossing stitched edges
+pub struct Ray<'a, G: ?Sized, T: Coord> {
+	grid: &'a G,
+	start: Point<T>,
+	point: Option<Point<T>>,
+	dir: Point<isize>,
+}
+
+impl<'a, G: ?Sized, T: Coord> Ray<'a, G, T> {
+	pub(crate) fn new(grid: &'a G, point: Point<T>, dir: Point<isize>) -> Self {
+		Ray { grid, start: point, point: Some(point), dir }
+	}
+}
+
+impl<'a, G, T> Iterator for Ray<'a, G, T>
+where
+	G: Grid<T> + ?Sized,
+	T: Coord
+{
+	type Item = Point<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let (point, dir) = self.grid.step(self.point?, self.dir)
+			.filter(|(p, _)| *p != self.start)
+			.unzip();
+		self.point = point;
+		self.dir = dir.unwrap_or(self.dir);
+		self.point
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::space_2D::ArrayGrid;
+
+	#[test]
+	fn test_neighbours() {
+		let grid = ArrayGrid::new(3, 3, 0);
+		let corner = Point::<usize>::from((0, 0));
+		let center = Point::<usize>::from((1, 1));
+
+		assert_eq!(grid.neighbours_4(corner).collect::<Vec<_>>(), vec![(EAST, (0, 1).into()), (SOUTH, (1, 0).into())]);
+		assert_eq!(grid.neighbours_8(corner).count(), 3);
+		assert_eq!(grid.neighbours_8(center).count(), 8);
+		assert_eq!(grid.neighbours(center, &OFFSETS_DIAGONAL).map(|(_, p)| p).last(), Some((2, 2).into()));
+		assert_eq!(grid.neighbours(Point::<isize>::from((-1, 0)), &[SOUTH, SOUTH_EAST]).count(), 2);
+	}
+
+	#[test]
+	fn test_dir_neighbours() {
+		let grid = ArrayGrid::new(2, 3, 0);
+		let corner = Point::<usize>::from((0, 2));
+		let inside: Vec<_> = grid.adjacents(corner).inside().collect();
+		assert_eq!(inside, vec![(Direction::West, (0, 1).into()), (Direction::South, (1, 2).into())]);
+		assert_eq!(grid.adjacents(corner).outside().collect::<Vec<_>>(), vec![Direction::North, Direction::East]);
+		assert_eq!(grid.adjacents(corner).count(), 4);
+
+		// a point out of the grid must not be clamped back into it
+		let vec_grid = vec![vec![0; 3]; 2];
+		assert_eq!(vec_grid.adjacents_4(Point::<usize>::from((0, 4))), vec![]);
+		assert_eq!(vec_grid.adjacents_4(Point::<isize>::from((-1, 1))), vec![Point::from((0, 1))]);
+
+		let diagonals = [Direction::NorthWest, Direction::SouthWest];
+		let neighbours: Vec<_> = grid.neighbours_dir(Point::<isize>::from((0, 0)), &diagonals).collect();
+		assert_eq!(neighbours, vec![(Direction::NorthWest, None), (Direction::SouthWest, None)]);
+	}
+
+	#[test]
+	fn test_ray() {
+		let grid = ArrayGrid::new(3, 4, 0);
+		let ray: Vec<Point> = grid.ray(Point::from((1, 1)), EAST).collect();
+		assert_eq!(ray, vec![(1, 2).into(), (1, 3).into()]);
+		assert_eq!(grid.ray(Point::<usize>::from((0, 0)), NORTH_WEST).next(), None);
+		assert_eq!(grid.ray(Point::<isize>::from((0, 0)), SOUTH_EAST).count(), 2);
+	}
+}
@@ -1,10 +1,54 @@
 use std::ops::*;
 use std::fmt;
+use std::hash::Hash;
+use std::num::TryFromIntError;
 
-/// trait used to limit T in Point<T> to isize and usize only
-pub trait Coord: Copy + Default {}
-impl Coord for isize {}
-impl Coord for usize {}
+/// trait used to limit T in Point<T> to the primitive integer types
+pub trait Coord: Copy + Default + Ord + Hash + fmt::Debug + 'static {
+	/// self + delta, or None if the result doesn't fit in the type
+	fn offset(self, delta: isize) -> Option<Self>;
+
+	/// absolute difference between self and other, i.e. the number of cells
+	/// between them
+	fn dist(self, other: Self) -> usize;
+}
+
+impl Coord for isize {
+	fn offset(self, delta: isize) -> Option<Self> {
+		self.checked_add(delta)
+	}
+
+	fn dist(self, other: Self) -> usize {
+		self.abs_diff(other)
+	}
+}
+
+impl Coord for usize {
+	fn offset(self, delta: isize) -> Option<Self> {
+		self.checked_add_signed(delta)
+	}
+
+	fn dist(self, other: Self) -> usize {
+		self.abs_diff(other)
+	}
+}
+
+macro_rules! impl_coord {
+	($($ty:ty),+) => {$(
+		impl Coord for $ty {
+			fn offset(self, delta: isize) -> Option<Self> {
+				let value = i128::try_from(self).ok()?.checked_add(delta as i128)?;
+				<$ty>::try_from(value).ok()
+			}
+
+			fn dist(self, other: Self) -> usize {
+				self.abs_diff(other) as usize
+			}
+		}
+	)+};
+}
+
+impl_coord!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
 
 /// coordenates of a 2D grid
 /// note that if thinking in rows and columns, Y are the rows and X are the
@@ -27,19 +71,112 @@ impl<T: Coord> Point<T> {
 	pub fn to_tuple(&self) -> (T, T) {
 		(self.y, self.x)
 	}
+
+	/// the point moved by delta, or None if it doesn't fit in T, i.e. a
+	/// Point<usize> moved to negative coordinates
+	pub fn offset(&self, delta: Point<isize>) -> Option<Point<T>> {
+		Some(Point { y: self.y.offset(delta.y)?, x: self.x.offset(delta.x)? })
+	}
 }
 
-impl Point<isize> {
-	pub fn signum(&self) -> (isize, isize) {
-		(if self.y == 0 {0} else {self.y / self.y.abs()},
-		 if self.x == 0 {0} else {self.x / self.x.abs()})
+impl<T: Coord> Point<T> {
+	/// convert to other integer type that can hold all the values of T, i.e.
+	/// from Point<i16> to Point<i32>
+	pub fn convert<U: Coord + From<T>>(&self) -> Point<U> {
+		Point { y: U::from(self.y), x: U::from(self.x) }
+	}
+
+	/// convert to other integer type, or None if a coordenate doesn't fit in it
+	pub fn try_convert<U: Coord + TryFrom<T>>(&self) -> Option<Point<U>> {
+		Some(Point { y: U::try_from(self.y).ok()?, x: U::try_from(self.x).ok()? })
 	}
 }
 
+macro_rules! impl_signed {
+	($($ty:ty),+) => {$(
+		impl Point<$ty> {
+			pub fn signum(&self) -> ($ty, $ty) {
+				(self.y.signum(), self.x.signum())
+			}
+		}
+
+		impl Sub for Point<$ty> {
+			type Output = Point<$ty>;
+			fn sub(self, rhs: Self) -> Self::Output {
+				Point::from((self.y - rhs.y, self.x - rhs.x))
+			}
+		}
+	)+};
+}
+
+impl_signed!(isize, i8, i16, i32, i64, i128);
+
+/// a - b as isize, or None if it doesn't fit
+macro_rules! signed_diff {
+	($a:expr, $b:expr) => {
+		match $a >= $b {
+			true => usize::try_from($a - $b).ok().and_then(|d| 0isize.checked_add_unsigned(d)),
+			false => usize::try_from($b - $a).ok().and_then(|d| 0isize.checked_sub_unsigned(d)),
+		}
+	};
+}
+
+macro_rules! impl_unsigned_sub {
+	($($ty:ty),+) => {$(
+		impl Point<$ty> {
+			/// self - rhs as a Point<isize>, or None if a coordenate doesn't fit in
+			/// isize, i.e. with u64 values far apart
+			pub fn checked_diff(&self, rhs: Self) -> Option<Point<isize>> {
+				Some(Point { y: signed_diff!(self.y, rhs.y)?, x: signed_diff!(self.x, rhs.x)? })
+			}
+		}
+
+		impl Sub for Point<$ty> {
+			type Output = Point<isize>;
+			/// return Point<isize>, as the difference can be negative. Panics if
+			/// it doesn't fit in isize, see checked_diff
+			fn sub(self, rhs: Self) -> Self::Output {
+				self.checked_diff(rhs).expect("Point difference out of the range of isize")
+			}
+		}
+	)+};
+}
+
+impl_unsigned_sub!(usize, u8, u16, u32, u64, u128);
+
 impl Point<usize> {
-	/// panics on underflow
+	/// panics on underflow or overflow, see checked_add_signed
 	pub fn add_signed(&self, other: Point<isize>) -> Point<usize> {
-		Self::from(other + self.clone().into())
+		self.checked_add_signed(other).expect("Point<usize> out of range")
+	}
+
+	/// self + other, or None on underflow or overflow, i.e. moving left from x = 0
+	pub fn checked_add_signed(&self, other: Point<isize>) -> Option<Point<usize>> {
+		self.offset(other)
+	}
+
+	/// self - other, or None on underflow
+	pub fn checked_sub(&self, other: Point<usize>) -> Option<Point<usize>> {
+		Some(Point { y: self.y.checked_sub(other.y)?, x: self.x.checked_sub(other.x)? })
+	}
+
+	/// self + other, clamping each coordenate to the range of usize
+	pub fn saturating_add_signed(&self, other: Point<isize>) -> Point<usize> {
+		Point { y: self.y.saturating_add_signed(other.y), x: self.x.saturating_add_signed(other.x) }
+	}
+
+	/// self - other, clamping each coordenate at 0
+	pub fn saturating_sub(&self, other: Point<usize>) -> Point<usize> {
+		Point { y: self.y.saturating_sub(other.y), x: self.x.saturating_sub(other.x) }
+	}
+
+	/// self + other, wrapping around a grid of size.y rows and size.x columns,
+	/// so moving left from x = 0 arrives at x = size.x - 1
+	pub fn wrapping_add_signed(&self, other: Point<isize>, size: Point<usize>) -> Point<usize> {
+		let wrap = |v: usize, delta: isize, len: usize| {
+			(v as isize + delta).rem_euclid(len as isize) as usize
+		};
+		Point { y: wrap(self.y, other.y, size.y), x: wrap(self.x, other.x, size.x) }
 	}
 }
 
@@ -57,15 +194,19 @@ impl<T: Coord> From<Point<T>> for (T, T) {
 	}
 }
 
-impl From<Point<usize>> for Point<isize> {
-	fn from(p: Point<usize>) -> Self {
-		Point::from((p.y as isize, p.x as isize))
+/// fails if any coordenate is above isize::MAX
+impl TryFrom<Point<usize>> for Point<isize> {
+	type Error = TryFromIntError;
+	fn try_from(p: Point<usize>) -> Result<Self, Self::Error> {
+		Ok(Point::from((isize::try_from(p.y)?, isize::try_from(p.x)?)))
 	}
 }
 
-impl From<Point<isize>> for Point<usize> {
-	fn from(p: Point<isize>) -> Self {
-		Point::from((p.y as usize, p.x as usize))
+/// fails if any coordenate is negative
+impl TryFrom<Point<isize>> for Point<usize> {
+	type Error = TryFromIntError;
+	fn try_from(p: Point<isize>) -> Result<Self, Self::Error> {
+		Ok(Point::from((usize::try_from(p.y)?, usize::try_from(p.x)?)))
 	}
 }
 
@@ -92,26 +233,11 @@ where
 	}
 }
 
-impl Sub for Point {
-	type Output = Point<isize>;
-	/// return Point<isize> to avoid panics or underflows
-	fn sub(self, rhs: Self) -> Self::Output {
-		Point::from((self.y as isize - rhs.y as isize, self.x as isize - rhs.x as isize))
-	}
-}
-
-impl Sub for Point<isize> {
-	type Output = Point<isize>;
-	fn sub(self, rhs: Self) -> Self::Output {
-		Point::from((self.y - rhs.y, self.x - rhs.x))
-	}
-}
-
 impl<T> SubAssign for Point<T>
 where
 	T: Coord + SubAssign<T>
 {
-	/// might panic with Point<usize>
+	/// might panic with Point<usize>, see checked_sub and saturating_sub
 	fn sub_assign(&mut self, rhs: Point<T>) {
 		self.x -= rhs.x;
 		self.y -= rhs.y;
@@ -172,3 +298,60 @@ where
 		write!(f, "{{x: {}, y: {}}}", self.x, self.y)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_usize_arithmetic() {
+		let p = Point::<usize>::from((1, 0));
+		let left = Point::from((0, -1));
+		assert_eq!(p.checked_add_signed(left), None);
+		assert_eq!(p.checked_add_signed(Point::from((-1, 2))), Some(Point::from((0, 2))));
+		assert_eq!(p.checked_sub(Point::from((1, 0))), Some(Point::from((0, 0))));
+		assert_eq!(p.checked_sub(Point::from((2, 0))), None);
+		assert_eq!(p.saturating_add_signed(Point::from((-3, -1))), Point::from((0, 0)));
+		assert_eq!(p.saturating_sub(Point::from((0, 5))), Point::from((1, 0)));
+		assert_eq!(p.wrapping_add_signed(left, Point::from((3, 4))), Point::from((1, 3)));
+		assert_eq!(p.wrapping_add_signed(Point::from((-2, 9)), Point::from((3, 4))), Point::from((2, 1)));
+
+		assert_eq!(Point::<usize>::try_from(Point::<isize>::from((2, 3))), Ok(Point::from((2, 3))));
+		assert!(Point::<usize>::try_from(left).is_err());
+		assert_eq!(Point::<isize>::try_from(Point::<usize>::from((2, 3))), Ok(Point::from((2, 3))));
+		assert!(Point::<isize>::try_from(Point::<usize>::from((0, isize::MAX as usize + 1))).is_err());
+	}
+
+	#[test]
+	fn test_unsigned_sub() {
+		let (small, big) = (Point::<u64>::from((0, 5)), Point::<u64>::from((u64::MAX, 3)));
+		assert_eq!(Point::<u8>::from((0, 200)) - Point::from((255, 0)), Point::from((-255, 200)));
+		assert_eq!(Point::<u128>::from((1, 1)) - Point::from((3, 0)), Point::from((-2, 1)));
+		assert_eq!(small.checked_diff(big), None);
+		assert_eq!(big.checked_diff(small), None);
+		let half = Point::<u64>::from((1 << 63, 0));
+		assert_eq!(Point::<u64>::default().checked_diff(half), Some(Point::from((isize::MIN, 0))));
+		assert_eq!(half.checked_diff(Point::default()), None);
+	}
+
+	#[test]
+	#[should_panic(expected = "out of the range of isize")]
+	fn test_unsigned_sub_overflow() {
+		let _ = Point::<u64>::from((u64::MAX, 0)) - Point::from((0, 0));
+	}
+
+	#[test]
+	fn test_coord_widths() {
+		let p = Point::<i16>::from((-3, 4));
+		assert_eq!(p - Point::from((1, 1)), Point::from((-4, 3)));
+		assert_eq!(p.signum(), (-1, 1));
+		assert_eq!(p.offset(Point::from((-1, 1))), Some(Point::from((-4, 5))));
+		assert_eq!(Point::<u8>::from((0, 255)).offset(Point::from((0, 1))), None);
+		assert_eq!(Point::<u8>::from((200, 1)).offset(Point::from((-150, 0))), Some(Point::from((50, 1))));
+		assert_eq!(Point::<u8>::from((1, 2)) - Point::from((2, 1)), Point::from((-1, 1)));
+
+		assert_eq!(p.convert::<i64>(), Point::from((-3, 4)));
+		assert_eq!(p.try_convert::<u32>(), None);
+		assert_eq!(Point::<i64>::from((300, 5)).try_convert::<u16>(), Some(Point::from((300, 5))));
+	}
+}
@@ -0,0 +1,246 @@
+use super::{Point, Coord, Grid, GridMut, Line};
+
+/// axis-aligned rectangle between the min (top-left) and max (bottom-right)
+/// corners, both included
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rect<T: Coord = usize> {
+	pub min: Point<T>,
+	pub max: Point<T>,
+}
+
+impl<T: Coord> Rect<T> {
+	/// rect between 2 opposite corners, in any order
+	pub fn new(a: Point<T>, b: Point<T>) -> Self {
+		Rect {
+			min: Point { y: a.y.min(b.y), x: a.x.min(b.x) },
+			max: Point { y: a.y.max(b.y), x: a.x.max(b.x) },
+		}
+	}
+
+	/// the smallest rect containing all the points, or None if there are no points
+	pub fn from_points(points: impl IntoIterator<Item = Point<T>>) -> Option<Self> {
+		let mut points = points.into_iter();
+		let first = points.next()?;
+		Some(points.fold(Rect::new(first, first), |rect, p| rect.include(p)))
+	}
+
+	/// the smallest rect containing self and the point
+	pub fn include(&self, point: Point<T>) -> Self {
+		self.union(&Rect::new(point, point))
+	}
+
+	pub fn contains(&self, point: Point<T>) -> bool {
+		(self.min.y..=self.max.y).contains(&point.y) && (self.min.x..=self.max.x).contains(&point.x)
+	}
+
+	/// the points in both rects, or None if they don't overlap
+	pub fn intersection(&self, other: &Rect<T>) -> Option<Self> {
+		let min = Point { y: self.min.y.max(other.min.y), x: self.min.x.max(other.min.x) };
+		let max = Point { y: self.max.y.min(other.max.y), x: self.max.x.min(other.max.x) };
+		match min.y <= max.y && min.x <= max.x {
+			true => Some(Rect { min, max }),
+			false => None,
+		}
+	}
+
+	/// the smallest rect containing both rects
+	pub fn union(&self, other: &Rect<T>) -> Self {
+		Rect {
+			min: Point { y: self.min.y.min(other.min.y), x: self.min.x.min(other.min.x) },
+			max: Point { y: self.max.y.max(other.max.y), x: self.max.x.max(other.max.x) },
+		}
+	}
+
+	/// number of rows
+	pub fn height(&self) -> usize {
+		self.max.y.dist(self.min.y) + 1
+	}
+
+	/// number of columns
+	pub fn width(&self) -> usize {
+		self.max.x.dist(self.min.x) + 1
+	}
+
+	/// number of points
+	pub fn area(&self) -> usize {
+		self.height() * self.width()
+	}
+
+	/// the rect with `by` more cells on each side, or less if negative. None if
+	/// it doesn't fit in T or it gets empty
+	pub fn expand(&self, by: isize) -> Option<Self> {
+		let min = self.min.offset(Point { y: -by, x: -by })?;
+		let max = self.max.offset(Point { y: by, x: by })?;
+		match min.y <= max.y && min.x <= max.x {
+			true => Some(Rect { min, max }),
+			false => None,
+		}
+	}
+
+	/// iterate over all the points, row after row
+	pub fn iter(&self) -> impl Iterator<Item = Point<T>> {
+		let rect = *self;
+		Line::new(rect.min, Point { y: rect.max.y, x: rect.min.x })
+			.flat_map(move |start| Line::new(start, Point { y: start.y, x: rect.max.x }))
+	}
+
+	/// iterate over the points of the edges, clockwise from the min corner
+	pub fn perimeter(&self) -> impl Iterator<Item = Point<T>> {
+		let (top_left, bottom_right) = (self.min, self.max);
+		let top_right = Point { y: self.min.y, x: self.max.x };
+		let bottom_left = Point { y: self.max.y, x: self.min.x };
+		let is_line = self.height() == 1 || self.width() == 1;
+		let left_len = match is_line {
+			true => 0,
+			false => self.height() - 2,
+		};
+
+		Line::new(top_left, match is_line { true => bottom_right, false => top_right })
+			.chain(Line::new(top_right, bottom_right).skip(1).filter(move |_| !is_line))
+			.chain(Line::new(bottom_right, bottom_left).skip(1).filter(move |_| !is_line))
+			.chain(Line::new(bottom_left, top_left).skip(1).take(left_len))
+	}
+}
+
+impl Rect<isize> {
+	/// the part of the rect without negative coordinates, or None if there's none
+	pub(crate) fn non_negative(&self) -> Option<Rect<usize>> {
+		let max = Point::<usize>::try_from(self.max).ok()?;
+		let min = Point { y: self.min.y.max(0) as usize, x: self.min.x.max(0) as usize };
+		Some(Rect { min, max })
+	}
+}
+
+/// view of the part of a grid inside a rect, returned by Grid::sub_grid, or by
+/// GridMut::sub_grid_mut to modify it. G is a reference to the inner grid. The
+/// points are the same as in the inner grid, but the ones out of the rect are
+/// not contained
+pub struct SubGrid<G, T: Coord> {
+	grid: G,
+	rect: Rect<T>,
+}
+
+impl<G, T: Coord> SubGrid<G, T> {
+	pub(crate) fn new(grid: G, rect: Rect<T>) -> Self {
+		SubGrid { grid, rect }
+	}
+
+	pub fn rect(&self) -> Rect<T> {
+		self.rect
+	}
+}
+
+impl<G, T> Grid<T> for SubGrid<G, T>
+where
+	G: Grid<T>,
+	T: Coord
+{
+	type Item = G::Item;
+
+	fn contains_point(&self, point: Point<T>) -> bool {
+		self.rect.contains(point) && self.grid.contains_point(point)
+	}
+
+	fn get_point(&self, point: Point<T>) -> Option<&Self::Item> {
+		match self.rect.contains(point) {
+			true => self.grid.get_point(point),
+			false => None,
+		}
+	}
+
+	fn iter_grid(&self) -> Box<dyn Iterator<Item = (Point<T>, &Self::Item)> + '_> {
+		self.grid.iter_rect(self.rect)
+	}
+
+	fn bounds(&self) -> Option<Rect<T>> {
+		self.grid.bounds()?.intersection(&self.rect)
+	}
+}
+
+impl<G, T> GridMut<T> for SubGrid<G, T>
+where
+	G: GridMut<T>,
+	T: Coord
+{
+	fn get_point_mut(&mut self, point: Point<T>) -> Option<&mut Self::Item> {
+		match self.rect.contains(point) {
+			true => self.grid.get_point_mut(point),
+			false => None,
+		}
+	}
+
+	fn iter_grid_mut(&mut self) -> Box<dyn Iterator<Item = (Point<T>, &mut Self::Item)> + '_> {
+		self.grid.iter_rect_mut(self.rect)
+	}
+
+	fn iter_rect_mut(&mut self, rect: Rect<T>) -> Box<dyn Iterator<Item = (Point<T>, &mut Self::Item)> + '_> {
+		match self.rect.intersection(&rect) {
+			Some(rect) => self.grid.iter_rect_mut(rect),
+			None => Box::new(std::iter::empty()),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::space_2D::{ArrayGrid, HashGrid};
+
+	#[test]
+	fn test_rect() {
+		let rect = Rect::<isize>::from_points([(2, -1), (0, 3), (1, 1)].map(Point::from)).unwrap();
+		assert_eq!(rect, Rect::new(Point::from((2, 3)), Point::from((0, -1))));
+		assert_eq!((rect.height(), rect.width(), rect.area()), (3, 5, 15));
+		assert!(rect.contains(Point::from((1, -1))));
+		assert!(!rect.contains(Point::from((3, 0))));
+
+		let other = Rect::new(Point::from((1, 2)), Point::from((5, 5)));
+		assert_eq!(rect.intersection(&other), Some(Rect::new(Point::from((1, 2)), Point::from((2, 3)))));
+		assert_eq!(rect.union(&other), Rect::new(Point::from((0, -1)), Point::from((5, 5))));
+		assert_eq!(rect.intersection(&Rect::new(Point::from((3, 0)), Point::from((3, 0)))), None);
+		assert_eq!(rect.expand(1).map(|r| r.area()), Some(5 * 7));
+		assert_eq!(rect.expand(-2), None);
+		assert_eq!(Rect::new(Point::<usize>::default(), Point::default()).expand(1), None);
+	}
+
+	#[test]
+	fn test_iter_and_perimeter() {
+		let rect = Rect::new(Point::<usize>::from((1, 1)), Point::from((3, 4)));
+		assert_eq!(rect.iter().count(), rect.area());
+		assert_eq!(rect.iter().nth(4), Some(Point::from((2, 1))));
+
+		let perimeter: Vec<_> = rect.perimeter().collect();
+		assert_eq!(perimeter.len(), 10);
+		assert_eq!(perimeter[..5], [(1, 1), (1, 2), (1, 3), (1, 4), (2, 4)].map(Point::from));
+		assert_eq!(perimeter.last(), Some(&Point::from((2, 1))));
+
+		let row = Rect::new(Point::<usize>::from((0, 0)), Point::from((0, 2)));
+		assert_eq!(row.perimeter().count(), 3);
+		assert_eq!(Rect::new(Point::<usize>::from((1, 1)), Point::from((1, 1))).perimeter().count(), 1);
+	}
+
+	#[test]
+	fn test_sub_grid() {
+		let mut grid = ArrayGrid::from_fn(4, 4, |p: Point| p.y * 4 + p.x);
+		let rect = Rect::new(Point::<usize>::from((1, 1)), Point::from((2, 3)));
+		assert_eq!(grid.iter_rect(rect).map(|(_, v)| *v).collect::<Vec<_>>(), vec![5, 6, 7, 9, 10, 11]);
+
+		let sub_grid = grid.sub_grid(rect);
+		assert_eq!(sub_grid.get_point(Point::from((0, 0))), None);
+		assert_eq!(sub_grid.adjacents_4(Point::from((1, 1))), vec![(1, 2).into(), (2, 1).into()]);
+		assert_eq!(sub_grid.iter_grid().count(), 6);
+
+		let mut sub_grid = grid.sub_grid_mut(rect);
+		let points: Vec<_> = sub_grid.iter_grid_mut().map(|(p, v)| { *v = 0; p }).collect();
+		assert_eq!(points, rect.iter().collect::<Vec<_>>());
+		assert_eq!(grid.as_slice().iter().filter(|v| **v == 0).count(), 7);
+
+		let corner = Rect::new(Point::<isize>::from((-1, -1)), Point::from((0, 1)));
+		let mut sub_grid = grid.sub_grid_mut(corner);
+		assert_eq!(sub_grid.iter_grid_mut().map(|(p, _)| p).collect::<Vec<_>>(), vec![(0, 0).into(), (0, 1).into()]);
+
+		let mut sparse: HashGrid<usize> = [(Point::from((5, 5)), 1)].into_iter().collect();
+		let rect = Rect::new(Point::from((0, 0)), Point::from((1, 1)));
+		assert_eq!(sparse.sub_grid_mut(rect).iter_grid_mut().count(), sparse.sub_grid(rect).iter_grid().count());
+	}
+}
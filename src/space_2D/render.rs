@@ -0,0 +1,178 @@
+use super::{Point, Coord, Grid, Rect, Line};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Write, BufWriter};
+use std::path::Path;
+
+/// Text renderer of a grid, returned by Grid::render, that draws each cell with
+/// a formatter function. Its Display impl writes a line for each row, like
+/// `println!("{}", grid.render(|v| *v as char).highlight(path, '*'))`.
+///
+/// The renderer draws the smallest rect containing all the points of the grid,
+/// or the rect passed to crop. Points not in the grid are drawn as blank spaces.
+pub struct Renderer<'a, G: ?Sized, T: Coord, F> {
+	grid: &'a G,
+	format: F,
+	rect: Option<Rect<T>>,
+	overlay: HashMap<Point<T>, char>,
+}
+
+impl<'a, G, T, F> Renderer<'a, G, T, F>
+where
+	G: Grid<T> + ?Sized,
+	T: Coord,
+	F: Fn(&G::Item) -> char
+{
+	pub(crate) fn new(grid: &'a G, format: F) -> Self {
+		Renderer { grid, format, rect: None, overlay: HashMap::new() }
+	}
+
+	/// draw only the points in rect
+	pub fn crop(mut self, rect: Rect<T>) -> Self {
+		self.rect = Some(rect);
+		self
+	}
+
+	/// draw the points with ch instead of their value
+	pub fn highlight(mut self, points: impl IntoIterator<Item = Point<T>>, ch: char) -> Self {
+		self.overlay.extend(points.into_iter().map(|p| (p, ch)));
+		self
+	}
+
+	/// draw with ch the segments joining each point with the next one. Panics if
+	/// a segment is not horizontal, vertical or diagonal
+	pub fn path(mut self, points: impl IntoIterator<Item = Point<T>>, ch: char) -> Self {
+		let mut points = points.into_iter();
+		if let Some(mut prev) = points.next() {
+			self.overlay.insert(prev, ch);
+			for point in points {
+				let (dy, dx) = (prev.y.dist(point.y), prev.x.dist(point.x));
+				assert!(dy == 0 || dx == 0 || dy == dx,
+					"Path segment from {:?} to {:?} is not horizontal, vertical or diagonal", prev, point);
+				self.overlay.extend(Line::new(prev, point).map(|p| (p, ch)));
+				prev = point;
+			}
+		}
+		self
+	}
+
+	/// the rendered cells, row after row
+	pub fn rows(&self) -> Vec<Vec<char>> {
+		let rect = match self.rect {
+			Some(rect) => rect,
+			None => match Rect::from_points(self.grid.iter_grid().map(|(p, _)| p)) {
+				Some(rect) => rect,
+				None => return Vec::new(),
+			},
+		};
+
+		let cells: Vec<char> = rect.iter()
+			.map(|p| match self.overlay.get(&p) {
+				Some(ch) => *ch,
+				None => self.grid.get_point(p).map_or(' ', &self.format),
+			})
+			.collect();
+		cells.chunks(rect.width()).map(|row| row.to_vec()).collect()
+	}
+
+	/// write a binary grayscale PGM image, with a pixel per cell and the gray
+	/// level of each rendered char given by shade
+	pub fn write_pgm(&self, writer: &mut impl Write, shade: impl Fn(char) -> u8) -> io::Result<()> {
+		self.write_netpbm(writer, "P5", |ch| vec![shade(ch)])
+	}
+
+	/// write a binary color PPM image, with a pixel per cell and the RGB color
+	/// of each rendered char given by color
+	pub fn write_ppm(&self, writer: &mut impl Write, color: impl Fn(char) -> [u8; 3]) -> io::Result<()> {
+		self.write_netpbm(writer, "P6", |ch| color(ch).to_vec())
+	}
+
+	/// save a PGM image to path, i.e. a frame of a simulation
+	pub fn save_pgm(&self, path: impl AsRef<Path>, shade: impl Fn(char) -> u8) -> io::Result<()> {
+		let mut writer = BufWriter::new(File::create(path)?);
+		self.write_pgm(&mut writer, shade)?;
+		writer.flush()
+	}
+
+	/// save a PPM image to path
+	pub fn save_ppm(&self, path: impl AsRef<Path>, color: impl Fn(char) -> [u8; 3]) -> io::Result<()> {
+		let mut writer = BufWriter::new(File::create(path)?);
+		self.write_ppm(&mut writer, color)?;
+		writer.flush()
+	}
+
+	fn write_netpbm(&self, writer: &mut impl Write, magic: &str, pixel: impl Fn(char) -> Vec<u8>) -> io::Result<()> {
+		let rows = self.rows();
+		let width = rows.first().map_or(0, |row| row.len());
+		write!(writer, "{}\n{} {}\n255\n", magic, width, rows.len())?;
+		for ch in rows.into_iter().flatten() {
+			writer.write_all(&pixel(ch))?;
+		}
+		Ok(())
+	}
+}
+
+impl<'a, G, T, F> fmt::Display for Renderer<'a, G, T, F>
+where
+	G: Grid<T> + ?Sized,
+	T: Coord,
+	F: Fn(&G::Item) -> char
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for row in self.rows() {
+			writeln!(f, "{}", row.into_iter().collect::<String>())?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::space_2D::{ArrayGrid, HashGrid};
+
+	#[test]
+	fn test_render_text() {
+		let grid = ArrayGrid::from_rows(vec![b"#..".to_vec(), b".#.".to_vec()]);
+		let render = |v: &u8| *v as char;
+		assert_eq!(Grid::<usize>::render(&grid, render).to_string(), "#..\n.#.\n");
+
+		let path = [Point::<usize>::from((0, 1)), Point::from((0, 2)), Point::from((1, 2))];
+		assert_eq!(grid.render(render).path(path, '*').to_string(), "#**\n.#*\n");
+		assert_eq!(grid.render(render).highlight([Point::<usize>::from((1, 0))], 'o').to_string(), "#..\no#.\n");
+
+		let crop = Rect::new(Point::<usize>::from((0, 1)), Point::from((2, 2)));
+		assert_eq!(grid.render(render).crop(crop).to_string(), "..\n#.\n  \n");
+
+		let sparse: HashGrid<bool, i32> = [(Point::from((-1, 3)), true), (Point::from((1, 4)), true)].into_iter().collect();
+		assert_eq!(sparse.render(|v| match v { true => '#', false => '.' }).to_string(), "#.\n..\n.#\n");
+	}
+
+	#[test]
+	fn test_render_path_diagonal() {
+		let grid = ArrayGrid::new(3, 3, b'.');
+		let diagonal = [Point::<usize>::from((0, 0)), Point::from((2, 2))];
+		assert_eq!(grid.render(|v| *v as char).path(diagonal, '*').to_string(), "*..\n.*.\n..*\n");
+	}
+
+	#[test]
+	#[should_panic(expected = "is not horizontal, vertical or diagonal")]
+	fn test_render_path_not_aligned() {
+		let grid = ArrayGrid::new(3, 3, b'.');
+		let _ = grid.render(|v| *v as char).path([Point::<usize>::from((0, 0)), Point::from((1, 2))], '*');
+	}
+
+	#[test]
+	fn test_render_images() {
+		let grid = ArrayGrid::from_rows(vec![b"#.".to_vec()]);
+		let render = |grid| Grid::<usize>::render(grid, |v: &u8| *v as char);
+		let mut pgm = Vec::new();
+		render(&grid).write_pgm(&mut pgm, |ch| match ch { '#' => 255, _ => 0 }).unwrap();
+		assert_eq!(pgm, b"P5\n2 1\n255\n\xff\x00");
+
+		let mut ppm = Vec::new();
+		render(&grid).write_ppm(&mut ppm, |ch| match ch { '#' => [255, 0, 0], _ => [0; 3] }).unwrap();
+		assert_eq!(ppm, b"P6\n2 1\n255\n\xff\x00\x00\x00\x00\x00");
+	}
+}
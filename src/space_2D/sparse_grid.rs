@@ -0,0 +1,262 @@
+use super::{Point, Grid, GridMut, Coord, Rect};
+use std::cell::Cell;
+use std::collections::{BTreeMap, HashMap};
+use std::ops;
+
+type Bounds<C> = Option<Rect<C>>;
+
+fn extend_bounds<C: Coord>(bounds: Bounds<C>, point: Point<C>) -> Bounds<C> {
+	match bounds {
+		None => Some(Rect::new(point, point)),
+		Some(rect) => Some(rect.include(point)),
+	}
+}
+
+fn is_on_edge<C: Coord>(bounds: Bounds<C>, point: Point<C>) -> bool {
+	match bounds {
+		None => false,
+		Some(Rect { min, max }) => point.y == min.y || point.y == max.y || point.x == min.x || point.x == max.x,
+	}
+}
+
+macro_rules! sparse_grid {
+	($(#[$doc:meta])* $name:ident, $map:ident) => {
+		$(#[$doc])*
+		///
+		/// Only the cells that have been set are stored, and the others have the
+		/// default value passed to new. It has no bounds, so it can be indexed with
+		/// any Point<C>, and it keeps track of the bounding box of the set cells.
+		/// C is isize by default, but a smaller type like i16 saves memory
+		#[derive(Clone, Debug)]
+		pub struct $name<T, C: Coord = isize> {
+			cells: $map<Point<C>, T>,
+			default: T,
+			bounds: Cell<Bounds<C>>,
+			bounds_dirty: Cell<bool>,
+		}
+
+		impl<T, C: Coord> $name<T, C> {
+			/// new empty grid, where all the cells have the default value
+			pub fn new(default: T) -> Self {
+				$name { cells: $map::new(), default, bounds: Cell::new(None), bounds_dirty: Cell::new(false) }
+			}
+
+			/// value of the cell, or the default value if it has not been set
+			pub fn get(&self, point: Point<C>) -> &T {
+				self.cells.get(&point).unwrap_or(&self.default)
+			}
+
+			/// mutable ref to the cell, that is set to the default value if it was not set
+			pub fn get_mut(&mut self, point: Point<C>) -> &mut T
+			where
+				T: Clone
+			{
+				if !self.cells.contains_key(&point) {
+					self.set(point, self.default.clone());
+				}
+				self.cells.get_mut(&point).unwrap()
+			}
+
+			/// set the value of the cell, returning the previous one if it was set
+			pub fn set(&mut self, point: Point<C>, value: T) -> Option<T> {
+				if !self.bounds_dirty.get() {
+					self.bounds.set(extend_bounds(self.bounds.get(), point));
+				}
+				self.cells.insert(point, value)
+			}
+
+			/// unset the cell, so it gets the default value again
+			pub fn remove(&mut self, point: Point<C>) -> Option<T> {
+				let value = self.cells.remove(&point)?;
+				if is_on_edge(self.bounds.get(), point) {
+					self.bounds_dirty.set(true);
+				}
+				Some(value)
+			}
+
+			/// if the cell has been set, even if it was set to the default value
+			pub fn is_set(&self, point: Point<C>) -> bool {
+				self.cells.contains_key(&point)
+			}
+
+			/// number of cells that have been set
+			pub fn len(&self) -> usize {
+				self.cells.len()
+			}
+
+			pub fn is_empty(&self) -> bool {
+				self.cells.is_empty()
+			}
+
+			pub fn clear(&mut self) {
+				self.cells.clear();
+				self.bounds.set(None);
+				self.bounds_dirty.set(false);
+			}
+
+			pub fn default_value(&self) -> &T {
+				&self.default
+			}
+
+			/// iterate over the cells that have been set
+			pub fn iter(&self) -> impl Iterator<Item = (Point<C>, &T)> + '_ {
+				self.cells.iter().map(|(p, v)| (*p, v))
+			}
+
+			/// points of the cells that have been set
+			pub fn points(&self) -> impl Iterator<Item = Point<C>> + '_ {
+				self.cells.keys().copied()
+			}
+
+			/// the smallest rect containing all the set cells, or None if it's empty
+			pub fn bounds(&self) -> Option<Rect<C>> {
+				if self.bounds_dirty.replace(false) {
+					self.bounds.set(self.cells.keys().fold(None, |b, p| extend_bounds(b, *p)));
+				}
+				self.bounds.get()
+			}
+		}
+
+		impl<T: Default, C: Coord> Default for $name<T, C> {
+			fn default() -> Self {
+				$name::new(T::default())
+			}
+		}
+
+		impl<T: Default, C: Coord> FromIterator<(Point<C>, T)> for $name<T, C> {
+			fn from_iter<I: IntoIterator<Item = (Point<C>, T)>>(iter: I) -> Self {
+				let mut grid = $name::default();
+				grid.extend(iter);
+				grid
+			}
+		}
+
+		impl<T, C: Coord> Extend<(Point<C>, T)> for $name<T, C> {
+			fn extend<I: IntoIterator<Item = (Point<C>, T)>>(&mut self, iter: I) {
+				for (point, value) in iter {
+					self.set(point, value);
+				}
+			}
+		}
+
+		/// the grid is unbounded: all the points are in it, and the ones that have
+		/// not been set have the default value. So contains_point is always true,
+		/// use is_set to know if a cell has been set. The neighbourhood helpers return
+		/// all the points, iter_rect all the points of the rect, but iter_grid only
+		/// the cells set
+		impl<T: Clone, C: Coord> Grid<C> for $name<T, C> {
+			type Item = T;
+
+			fn contains_point(&self, _point: Point<C>) -> bool {
+				true
+			}
+
+			fn get_point(&self, point: Point<C>) -> Option<&Self::Item> {
+				Some(self.get(point))
+			}
+
+			/// the grid has no bounds, so any step is possible if it fits in C
+			fn step(&self, point: Point<C>, dir: Point<isize>) -> Option<(Point<C>, Point<isize>)> {
+				point.offset(dir).map(|p| (p, dir))
+			}
+
+			fn iter_grid(&self) -> Box<dyn Iterator<Item = (Point<C>, &Self::Item)> + '_> {
+				Box::new(self.iter())
+			}
+
+			fn bounds(&self) -> Option<Rect<C>> {
+				$name::bounds(self)
+			}
+		}
+
+		impl<T: Clone, C: Coord> GridMut<C> for $name<T, C> {
+			fn get_point_mut(&mut self, point: Point<C>) -> Option<&mut Self::Item> {
+				Some(self.get_mut(point))
+			}
+
+			fn iter_grid_mut(&mut self) -> Box<dyn Iterator<Item = (Point<C>, &mut Self::Item)> + '_> {
+				Box::new(self.cells.iter_mut().map(|(p, v)| (*p, v)))
+			}
+
+			/// all the points of rect are in the grid, so the ones not set yet are
+			/// set to the default value, like get_point_mut does
+			fn iter_rect_mut(&mut self, rect: Rect<C>) -> Box<dyn Iterator<Item = (Point<C>, &mut Self::Item)> + '_> {
+				for point in rect.iter() {
+					if !self.is_set(point) {
+						self.set(point, self.default.clone());
+					}
+				}
+				Box::new(self.cells.iter_mut().filter(move |(p, _)| rect.contains(**p)).map(|(p, v)| (*p, v)))
+			}
+		}
+
+		impl<T, C: Coord> ops::Index<Point<C>> for $name<T, C> {
+			type Output = T;
+			fn index(&self, index: Point<C>) -> &Self::Output {
+				self.get(index)
+			}
+		}
+
+		impl<T: Clone, C: Coord> ops::IndexMut<Point<C>> for $name<T, C> {
+			fn index_mut(&mut self, index: Point<C>) -> &mut Self::Output {
+				self.get_mut(index)
+			}
+		}
+	};
+}
+
+sparse_grid!(
+	/// Sparse 2D Grid stored in a HashMap, for fast access to random cells.
+	HashGrid, HashMap
+);
+
+sparse_grid!(
+	/// Sparse 2D Grid stored in a BTreeMap, that iterates the cells in order,
+	/// row after row.
+	BTreeGrid, BTreeMap
+);
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::space_2D::Transform;
+
+	#[test]
+	fn test_default_and_bounds() {
+		let mut grid = BTreeGrid::new('.');
+		assert_eq!(grid.bounds(), None);
+		assert_eq!(grid[Point::from((-100, 100))], '.');
+
+		grid.set(Point::from((2, -3)), '#');
+		grid[Point::from((-1, 5))] = '#';
+		grid.set(Point::from((0, 0)), '#');
+		assert_eq!(grid.len(), 3);
+		assert_eq!(grid.bounds(), Some(Rect::new(Point::from((-1, -3)), Point::from((2, 5)))));
+
+		grid.remove(Point::from((-1, 5)));
+		assert_eq!(grid.bounds(), Some(Rect::new(Point::from((0, -3)), Point::from((2, 0)))));
+		assert_eq!(grid.points().collect::<Vec<_>>(), vec![(0, 0).into(), (2, -3).into()]);
+	}
+
+	#[test]
+	fn test_grid_trait() {
+		let mut grid: HashGrid<u32> = [(Point::from((0, 0)), 1)].into_iter().collect();
+		assert!(grid.contains_point(Point::from((0, 1))));
+		assert!(grid.is_set(Point::from((0, 0))));
+		assert!(!grid.is_set(Point::from((0, 1))));
+		assert_eq!(grid.get_point(Point::from((7, 7))), Some(&0));
+
+		*grid.get_point_mut(Point::from((0, 1))).unwrap() += 5;
+		assert_eq!(grid[Point::from((0, 1))], 5);
+		assert_eq!(grid.adjacents_4(Point::from((0, 0))).len(), 4);
+		assert_eq!(grid.adjacents(Point::from((5, 5))).inside().count(), 4);
+		assert_eq!(grid.ray(Point::from((0, 0)), Point::from((0, 1))).nth(10), Some(Point::from((0, 11))));
+		assert_eq!(grid.iter_grid().map(|(_, v)| v).sum::<u32>(), 6);
+
+		let rect = Rect::new(Point::from((-1, -1)), Point::from((1, 1)));
+		assert_eq!(grid.iter_rect(rect).count(), 9);
+		assert_eq!(grid.sub_grid(rect).iter_grid().map(|(_, v)| v).sum::<u32>(), 6);
+		let view = grid.view(Transform::Rotate90);
+		assert_eq!((view.height(), view.width()), (2, 1));
+	}
+}
@@ -0,0 +1,325 @@
+use super::{Point, Grid, GridMut, Rect};
+use std::collections::HashMap;
+use std::ops;
+
+/// how the edges of a grid are connected, to know where a step from a point in
+/// a direction (i.e. `(0, 1)` to move right) arrives.
+///
+/// It returns the new position and the new direction, that can be different if
+/// the step crosses a stitched edge, or None if the step is not possible.
+pub trait Topology<G> {
+	fn step(&self, grid: &G, point: Point<isize>, dir: Point<isize>) -> Option<(Point<isize>, Point<isize>)>;
+}
+
+/// the grid ends at its edges
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Bounded;
+
+impl<G: Grid<isize>> Topology<G> for Bounded {
+	fn step(&self, grid: &G, point: Point<isize>, dir: Point<isize>) -> Option<(Point<isize>, Point<isize>)> {
+		let next = point + dir;
+		match grid.contains_point(next) {
+			true => Some((next, dir)),
+			false => None,
+		}
+	}
+}
+
+/// the opposite edges of a grid of height x width are connected, so leaving by
+/// the right arrives at the left of the same row, and leaving by the bottom at
+/// the top of the same column
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Torus {
+	pub height: usize,
+	pub width: usize,
+}
+
+impl Torus {
+	pub fn new(height: usize, width: usize) -> Self {
+		Torus { height, width }
+	}
+
+	/// the point inside the grid equivalent to any point, i.e. after moving
+	/// many steps at once, like `torus.wrap(pos0 + vel * minute)`
+	pub fn wrap(&self, point: Point<isize>) -> Point<isize> {
+		Point::from((point.y.rem_euclid(self.height as isize), point.x.rem_euclid(self.width as isize)))
+	}
+}
+
+impl<G> Topology<G> for Torus {
+	fn step(&self, _grid: &G, point: Point<isize>, dir: Point<isize>) -> Option<(Point<isize>, Point<isize>)> {
+		Some((self.wrap(point + dir), dir))
+	}
+}
+
+/// like a torus, but each row and column wraps around its non blank cells only,
+/// i.e. leaving to the right arrives at the first non blank cell of the row.
+/// It's useful for maps with irregular shapes, padded with blank cells. The
+/// search for the cell to wrap to stops at the bounds of the grid, so the step
+/// is None for the direction (0, 0) or from a row or column fully blank
+#[derive(Clone, Copy, Debug)]
+pub struct WrapNonBlank<T> {
+	pub blank: T,
+}
+
+impl<T> WrapNonBlank<T> {
+	pub fn new(blank: T) -> Self {
+		WrapNonBlank { blank }
+	}
+}
+
+impl<G, T> Topology<G> for WrapNonBlank<T>
+where
+	G: Grid<isize, Item = T>,
+	T: PartialEq
+{
+	fn step(&self, grid: &G, point: Point<isize>, dir: Point<isize>) -> Option<(Point<isize>, Point<isize>)> {
+		if dir == Point::default() {
+			return None;
+		}
+		let is_cell = |p| grid.get_point(p).is_some_and(|v| *v != self.blank);
+		if is_cell(point + dir) {
+			return Some((point + dir, dir));
+		}
+
+		// walk back along the row or column up to the bounds of the grid, as an
+		// unbounded grid has cells in every direction
+		let bounds = grid.bounds()?;
+		let mut wrapped = point;
+		while bounds.contains(wrapped - dir) && is_cell(wrapped - dir) {
+			wrapped -= dir;
+		}
+		match is_cell(wrapped) {
+			true => Some((wrapped, dir)),
+			false => None,
+		}
+	}
+}
+
+/// a position and a direction
+type Move = (Point<isize>, Point<isize>);
+
+/// the edges are stitched as defined by the user, i.e. to fold the map of a
+/// cube's faces. Steps that don't cross a stitched edge are bounded
+#[derive(Clone, Debug, Default)]
+pub struct Stitched {
+	stitches: HashMap<Move, Move>,
+}
+
+impl Stitched {
+	pub fn new() -> Self {
+		Default::default()
+	}
+
+	/// leaving `from` in the direction `leave` arrives at `to`, facing `enter`.
+	/// The stitch works both ways: leaving `to` in the opposite direction to
+	/// `enter` arrives at `from`, facing the opposite direction to `leave`
+	pub fn stitch(mut self, from: Point<isize>, leave: Point<isize>, to: Point<isize>, enter: Point<isize>) -> Self {
+		self.stitches.insert((from, leave), (to, enter));
+		self.stitches.insert((to, Point::default() - enter), (from, Point::default() - leave));
+		self
+	}
+
+	/// stitch len cells of two edges: the cells from `from` advancing `from_step`
+	/// each time are stitched with the cells from `to` advancing `to_step`
+	#[allow(clippy::too_many_arguments)]
+	pub fn stitch_edge(
+		mut self,
+		from: Point<isize>, from_step: Point<isize>, leave: Point<isize>,
+		to: Point<isize>, to_step: Point<isize>, enter: Point<isize>,
+		len: usize
+	) -> Self {
+		for n in 0..len as isize {
+			self = self.stitch(from + from_step * n, leave, to + to_step * n, enter);
+		}
+		self
+	}
+}
+
+impl<G: Grid<isize>> Topology<G> for Stitched {
+	fn step(&self, grid: &G, point: Point<isize>, dir: Point<isize>) -> Option<(Point<isize>, Point<isize>)> {
+		match self.stitches.get(&(point, dir)) {
+			Some(&stitched) => Some(stitched),
+			None => Bounded.step(grid, point, dir),
+		}
+	}
+}
+
+/// grid adapter that moves around the inner grid according to a Topology, like
+/// `TopoGrid::new(grid, Torus::new(height, width))`. Grid::step, and so the
+/// adjacents, neighbours and rays, follow the wraps and stitched edges
+#[derive(Clone, Debug)]
+pub struct TopoGrid<G, Tp> {
+	grid: G,
+	topology: Tp,
+}
+
+impl<G, Tp: Topology<G>> TopoGrid<G, Tp> {
+	pub fn new(grid: G, topology: Tp) -> Self {
+		TopoGrid { grid, topology }
+	}
+
+	pub fn grid(&self) -> &G {
+		&self.grid
+	}
+
+	pub fn grid_mut(&mut self) -> &mut G {
+		&mut self.grid
+	}
+
+	pub fn topology(&self) -> &Tp {
+		&self.topology
+	}
+
+	pub fn into_inner(self) -> G {
+		self.grid
+	}
+}
+
+impl<G, Tp> Grid<isize> for TopoGrid<G, Tp>
+where
+	G: Grid<isize>,
+	Tp: Topology<G>
+{
+	type Item = G::Item;
+
+	fn contains_point(&self, point: Point<isize>) -> bool {
+		self.grid.contains_point(point)
+	}
+
+	fn get_point(&self, point: Point<isize>) -> Option<&Self::Item> {
+		self.grid.get_point(point)
+	}
+
+	fn step(&self, point: Point<isize>, dir: Point<isize>) -> Option<(Point<isize>, Point<isize>)> {
+		self.topology.step(&self.grid, point, dir)
+	}
+
+	fn iter_grid(&self) -> Box<dyn Iterator<Item = (Point<isize>, &Self::Item)> + '_> {
+		self.grid.iter_grid()
+	}
+
+	fn bounds(&self) -> Option<Rect<isize>> {
+		self.grid.bounds()
+	}
+}
+
+impl<G, Tp> GridMut<isize> for TopoGrid<G, Tp>
+where
+	G: GridMut<isize>,
+	Tp: Topology<G>
+{
+	fn get_point_mut(&mut self, point: Point<isize>) -> Option<&mut Self::Item> {
+		self.grid.get_point_mut(point)
+	}
+
+	fn iter_grid_mut(&mut self) -> Box<dyn Iterator<Item = (Point<isize>, &mut Self::Item)> + '_> {
+		self.grid.iter_grid_mut()
+	}
+
+	fn iter_rect_mut(&mut self, rect: Rect<isize>) -> Box<dyn Iterator<Item = (Point<isize>, &mut Self::Item)> + '_> {
+		self.grid.iter_rect_mut(rect)
+	}
+}
+
+impl<G: ops::Index<Point<isize>>, Tp> ops::Index<Point<isize>> for TopoGrid<G, Tp> {
+	type Output = G::Output;
+	fn index(&self, index: Point<isize>) -> &Self::Output {
+		&self.grid[index]
+	}
+}
+
+impl<G: ops::IndexMut<Point<isize>>, Tp> ops::IndexMut<Point<isize>> for TopoGrid<G, Tp> {
+	fn index_mut(&mut self, index: Point<isize>) -> &mut Self::Output {
+		&mut self.grid[index]
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::space_2D::{ArrayGrid, HashGrid};
+
+	const RIGHT: (isize, isize) = (0, 1);
+	const UP: (isize, isize) = (-1, 0);
+
+	fn step<Tp>(grid: &TopoGrid<ArrayGrid<u8>, Tp>, p: (isize, isize), dir: (isize, isize)) -> Option<(Point<isize>, Point<isize>)>
+	where
+		Tp: Topology<ArrayGrid<u8>>
+	{
+		grid.step(p.into(), dir.into())
+	}
+
+	fn map() -> ArrayGrid<u8> {
+		ArrayGrid::from_rows(vec![b" ..".to_vec(), b"...".to_vec(), b".. ".to_vec()])
+	}
+
+	#[test]
+	fn test_bounded_torus() {
+		let bounded = TopoGrid::new(map(), Bounded);
+		assert_eq!(step(&bounded, (1, 2), RIGHT), None);
+		assert_eq!(bounded.adjacents_4((0, 0).into()).len(), 2);
+
+		let torus = TopoGrid::new(map(), Torus::new(3, 3));
+		assert_eq!(step(&torus, (1, 2), RIGHT), Some(((1, 0).into(), RIGHT.into())));
+		assert_eq!(step(&torus, (0, 1), UP), Some(((2, 1).into(), UP.into())));
+		assert_eq!(torus.adjacents_4((0, 0).into()).len(), 4);
+		assert_eq!(Torus::new(3, 3).wrap((-7, 10).into()), (2, 1).into());
+	}
+
+	#[test]
+	fn test_wrap_non_blank() {
+		let grid = TopoGrid::new(map(), WrapNonBlank::new(b' '));
+		assert_eq!(step(&grid, (0, 2), RIGHT), Some(((0, 1).into(), RIGHT.into())));
+		assert_eq!(step(&grid, (0, 0), RIGHT), Some(((0, 1).into(), RIGHT.into())));
+		assert_eq!(step(&grid, (1, 0), UP), Some(((2, 0).into(), UP.into())));
+		assert_eq!(step(&grid, (1, 1), RIGHT), Some(((1, 2).into(), RIGHT.into())));
+		assert_eq!(step(&grid, (1, 1), (0, 0)), None);
+
+		let blank_row = ArrayGrid::from_rows(vec![b"..".to_vec(), b"  ".to_vec()]);
+		let grid = TopoGrid::new(blank_row, WrapNonBlank::new(b' '));
+		assert_eq!(step(&grid, (1, 1), RIGHT), None);
+		assert_eq!(step(&grid, (0, 1), RIGHT), Some(((0, 0).into(), RIGHT.into())));
+
+		// the unset cells of a sparse grid are not blank, the search stops at its bounds
+		let sparse: HashGrid<u8, isize> = [(Point::from((0, 0)), b'.'), (Point::from((0, 3)), b' ')].into_iter().collect();
+		let grid = TopoGrid::new(sparse, WrapNonBlank::new(b' '));
+		assert_eq!(grid.step((0, 2).into(), RIGHT.into()), Some(((0, 0).into(), RIGHT.into())));
+	}
+
+	#[test]
+	fn test_stitched() {
+		// leaving the top row upwards arrives at the left column, facing right
+		let stitched = Stitched::new()
+			.stitch_edge((0, 0).into(), (0, 1).into(), UP.into(), (0, 0).into(), (1, 0).into(), RIGHT.into(), 3);
+		let grid = TopoGrid::new(map(), stitched);
+		assert_eq!(step(&grid, (0, 2), UP), Some(((2, 0).into(), RIGHT.into())));
+		assert_eq!(step(&grid, (2, 0), (0, -1)), Some(((0, 2).into(), (1, 0).into())));
+		assert_eq!(step(&grid, (1, 1), RIGHT), Some(((1, 2).into(), RIGHT.into())));
+		assert_eq!(step(&grid, (1, 2), RIGHT), None);
+	}
+
+	#[test]
+	fn test_neighbours_and_rays() {
+		use crate::space_2D::Direction;
+
+		let torus = TopoGrid::new(map(), Torus::new(3, 3));
+		let adjacents: Vec<_> = torus.adjacents((0, 0).into()).inside().map(|(_, p)| p).collect();
+		assert_eq!(adjacents, torus.adjacents_4((0, 0).into()));
+		assert_eq!(adjacents, vec![(2, 0).into(), (0, 2).into(), (0, 1).into(), (1, 0).into()]);
+		assert_eq!(torus.neighbours_dir((0, 0).into(), &Direction::ALL_8).outside().count(), 0);
+		assert_eq!(torus.neighbours_8((1, 1).into()).count(), 8);
+		assert_eq!(torus.ray((1, 0).into(), RIGHT.into()).collect::<Vec<_>>(), vec![(1, 1).into(), (1, 2).into()]);
+
+		let wrap = TopoGrid::new(map(), WrapNonBlank::new(b' '));
+		assert_eq!(wrap.ray((0, 1).into(), RIGHT.into()).collect::<Vec<_>>(), vec![(0, 2).into()]);
+		assert_eq!(wrap.adjacents((0, 1).into()).outside().collect::<Vec<_>>(), vec![]);
+
+		// the ray turns right when crossing the stitched top edge
+		let stitched = Stitched::new()
+			.stitch_edge((0, 0).into(), (0, 1).into(), UP.into(), (0, 0).into(), (1, 0).into(), RIGHT.into(), 3);
+		let grid = TopoGrid::new(map(), stitched);
+		let ray: Vec<_> = grid.ray((1, 2).into(), UP.into()).collect();
+		assert_eq!(ray, vec![(0, 2).into(), (2, 0).into(), (2, 1).into(), (2, 2).into()]);
+	}
+}
@@ -1,4 +1,4 @@
-use super::{Point, Grid};
+use super::{Point, Grid, GridMut, Rect};
 use std::ops;
 
 /// 2D Grid implemented with Vecs. Can be indexed with Point<usize>
@@ -8,7 +8,7 @@ impl<T> Grid<usize> for VecGrid<T> {
 	type Item = T;
 
 	fn contains_point(&self, point: Point<usize>) -> bool {
-		(0..self.len()).contains(&point.y) && (0..self[0].len()).contains(&point.x)
+		point.y < self.len() && point.x < self[point.y].len()
 	}
 	
 	fn get_point(&self, point: Point<usize>) -> Option<&Self::Item> {
@@ -18,6 +18,21 @@ impl<T> Grid<usize> for VecGrid<T> {
 		}
 	}
 
+	fn iter_grid(&self) -> Box<dyn Iterator<Item = (Point<usize>, &Self::Item)> + '_> {
+		Box::new(self.iter().enumerate().flat_map(|(y, row)| {
+			row.iter().enumerate().map(move |(x, v)| (Point::from((y, x)), v))
+		}))
+	}
+
+	/// rows can have different lengths, so it's the rect of the longest one
+	fn bounds(&self) -> Option<Rect<usize>> {
+		let last_row = self.iter().rposition(|row| !row.is_empty())?;
+		let width = self.iter().map(|row| row.len()).max()?;
+		Some(Rect::new(Point::default(), Point::from((last_row, width - 1))))
+	}
+}
+
+impl<T> GridMut<usize> for VecGrid<T> {
 	fn get_point_mut(&mut self, point: Point<usize>) -> Option<&mut Self::Item> {
 		match self.contains_point(point) {
 			true => Some(&mut self[point.y][point.x]),
@@ -25,29 +40,17 @@ impl<T> Grid<usize> for VecGrid<T> {
 		}
 	}
 
-	fn adjacents_4(&self, point: Point<usize>) -> Vec<Point<usize>> {
-		let (y, x) = (point.y as isize, point.x as isize);
-		let (y_max, x_max) = (self.len() as isize - 1, self[0].len() as isize - 1);
-
-		[(-1, 0), (0, -1), (0, 1), (1, 0)].iter().map(|p| {
-			Point::from((
-				(y + p.0).clamp(0, y_max) as usize,
-				(x + p.1).clamp(0, x_max) as usize
-			))
-		}).filter(|p| *p != point).collect()
-	}
-
-	fn iter_grid(&self) -> Box<dyn Iterator<Item = (Point<usize>, &Self::Item)> + '_> {
-		Box::new(self.iter().enumerate().flat_map(|(y, row)| {
-			row.iter().enumerate().map(move |(x, v)| (Point::from((y, x)), v))
-		}))
-	}
-
 	fn iter_grid_mut(&mut self) -> Box<dyn Iterator<Item = (Point<usize>, &mut Self::Item)> + '_> {
 		Box::new(self.iter_mut().enumerate().flat_map(|(y, row)| {
 			row.iter_mut().enumerate().map(move |(x, v)| (Point::from((y, x)), v))
 		}))
 	}
+
+	fn iter_rect_mut(&mut self, rect: Rect<usize>) -> Box<dyn Iterator<Item = (Point<usize>, &mut Self::Item)> + '_> {
+		Box::new(self.iter_mut().enumerate().skip(rect.min.y).take(rect.height()).flat_map(move |(y, row)| {
+			row.iter_mut().enumerate().skip(rect.min.x).take(rect.width()).map(move |(x, v)| (Point::from((y, x)), v))
+		}))
+	}
 }
 
 impl<T> ops::Index<Point<usize>> for VecGrid<T> {
@@ -72,7 +75,7 @@ impl<T> Grid<isize> for VecGrid<T> {
 	type Item = T;
 
 	fn contains_point(&self, point: Point<isize>) -> bool {
-		(0..self.len() as isize).contains(&point.y) && (0..self[0].len() as isize).contains(&point.x)
+		Point::<usize>::try_from(point).is_ok_and(|p| self.contains_point(p))
 	}
 
 	fn get_point(&self, point: Point<isize>) -> Option<&Self::Item> {
@@ -82,6 +85,19 @@ impl<T> Grid<isize> for VecGrid<T> {
 		}
 	}
 
+	fn iter_grid(&self) -> Box<dyn Iterator<Item = (Point<isize>, &Self::Item)> + '_> {
+		Box::new(self.iter().enumerate().flat_map(|(y, row)| {
+			row.iter().enumerate().map(move |(x, v)| (Point::from((y as isize, x as isize)), v))
+		}))
+	}
+
+	fn bounds(&self) -> Option<Rect<isize>> {
+		let rect = Grid::<usize>::bounds(self)?;
+		Some(Rect::new(rect.min.try_into().ok()?, rect.max.try_into().ok()?))
+	}
+}
+
+impl<T> GridMut<isize> for VecGrid<T> {
 	fn get_point_mut(&mut self, point: Point<isize>) -> Option<&mut Self::Item> {
 		match self.contains_point(point) {
 			true => Some(&mut self[point.y as usize][point.x as usize]),
@@ -89,28 +105,19 @@ impl<T> Grid<isize> for VecGrid<T> {
 		}
 	}
 
-	fn adjacents_4(&self, point: Point<isize>) -> Vec<Point<isize>> {
-		let (y_max, x_max) = (self.len() as isize - 1, self[0].len() as isize - 1);
-
-		[(-1, 0), (0, -1), (0, 1), (1, 0)].iter().map(|p| {
-			Point::from((
-				(point.y + p.0).clamp(0, y_max),
-				(point.x + p.1).clamp(0, x_max)
-			))
-		}).filter(|p| *p != point).collect()
-	}
-
-	fn iter_grid(&self) -> Box<dyn Iterator<Item = (Point<isize>, &Self::Item)> + '_> {
-		Box::new(self.iter().enumerate().flat_map(|(y, row)| {
-			row.iter().enumerate().map(move |(x, v)| (Point::from((y as isize, x as isize)), v))
-		}))
-	}
-
 	fn iter_grid_mut(&mut self) -> Box<dyn Iterator<Item = (Point<isize>, &mut Self::Item)> + '_> {
 		Box::new(self.iter_mut().enumerate().flat_map(|(y, row)| {
 			row.iter_mut().enumerate().map(move |(x, v)| (Point::from((y as isize, x as isize)), v))
 		}))
 	}
+
+	fn iter_rect_mut(&mut self, rect: Rect<isize>) -> Box<dyn Iterator<Item = (Point<isize>, &mut Self::Item)> + '_> {
+		match rect.non_negative() {
+			Some(rect) => Box::new(GridMut::<usize>::iter_rect_mut(self, rect)
+				.map(|(p, v)| (Point::from((p.y as isize, p.x as isize)), v))),
+			None => Box::new(std::iter::empty()),
+		}
+	}
 }
 
 impl<T> ops::Index<Point<isize>> for VecGrid<T> {
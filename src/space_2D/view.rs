@@ -0,0 +1,231 @@
+use super::{Point, Coord, Grid, GridMut, Rect};
+use std::collections::HashMap;
+use std::ops;
+
+/// the 8 ways to rotate or flip a grid, rotating clockwise with rows growing
+/// downwards
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Transform {
+	Identity,
+	/// rotate 90º clockwise, so the left column becomes the top row
+	Rotate90,
+	Rotate180,
+	/// rotate 270º clockwise, so the right column becomes the top row
+	Rotate270,
+	/// mirror left to right
+	FlipX,
+	/// mirror top to bottom
+	FlipY,
+	/// swap rows and columns
+	Transpose,
+	/// swap rows and columns through the other diagonal
+	AntiTranspose,
+}
+
+impl Transform {
+	pub const ALL: [Transform; 8] = [
+		Transform::Identity, Transform::Rotate90, Transform::Rotate180, Transform::Rotate270,
+		Transform::FlipX, Transform::FlipY, Transform::Transpose, Transform::AntiTranspose,
+	];
+
+	/// if the rows and the columns are swapped
+	pub fn swaps_axes(self) -> bool {
+		matches!(self, Transform::Rotate90 | Transform::Rotate270 | Transform::Transpose | Transform::AntiTranspose)
+	}
+
+	/// position in a grid of height x width of the cell that is at point after
+	/// the transform. The point must be inside the transformed grid
+	fn source(self, point: Point<usize>, height: usize, width: usize) -> Point<usize> {
+		let (y, x) = point.to_tuple();
+		Point::from(match self {
+			Transform::Identity      => (y, x),
+			Transform::Rotate90      => (height - 1 - x, y),
+			Transform::Rotate180     => (height - 1 - y, width - 1 - x),
+			Transform::Rotate270     => (x, width - 1 - y),
+			Transform::FlipX         => (y, width - 1 - x),
+			Transform::FlipY         => (height - 1 - y, x),
+			Transform::Transpose     => (x, y),
+			Transform::AntiTranspose => (height - 1 - x, width - 1 - y),
+		})
+	}
+}
+
+/// view of a grid, or a window of it, after a Transform, returned by
+/// Grid::view and Grid::view_rect, or their _mut versions in GridMut to modify
+/// it. G is a reference to the inner grid, and no cells are copied. The view is
+/// indexed with Point<usize> from (0, 0) at its top-left corner, and to_original
+/// maps its points back to the points of the inner grid
+pub struct GridView<G, T: Coord> {
+	grid: G,
+	rect: Option<Rect<T>>,
+	transform: Transform,
+}
+
+impl<G, T> GridView<G, T>
+where
+	G: Grid<T>,
+	T: Coord
+{
+	pub(crate) fn new(grid: G, rect: Option<Rect<T>>, transform: Transform) -> Self {
+		GridView { grid, rect, transform }
+	}
+
+	/// number of rows of the view
+	pub fn height(&self) -> usize {
+		match (self.rect, self.transform.swaps_axes()) {
+			(None, _) => 0,
+			(Some(rect), false) => rect.height(),
+			(Some(rect), true) => rect.width(),
+		}
+	}
+
+	/// number of columns of the view
+	pub fn width(&self) -> usize {
+		match (self.rect, self.transform.swaps_axes()) {
+			(None, _) => 0,
+			(Some(rect), false) => rect.width(),
+			(Some(rect), true) => rect.height(),
+		}
+	}
+
+	pub fn transform(&self) -> Transform {
+		self.transform
+	}
+
+	/// the point of the inner grid for a point of the view, or None if it's out
+	/// of the view
+	pub fn to_original(&self, point: Point<usize>) -> Option<Point<T>> {
+		let rect = self.rect?;
+		match point.y < self.height() && point.x < self.width() {
+			true => {
+				let source = self.transform.source(point, rect.height(), rect.width());
+				rect.min.offset(Point { y: source.y as isize, x: source.x as isize })
+			},
+			false => None,
+		}
+	}
+}
+
+impl<G, T> Grid<usize> for GridView<G, T>
+where
+	G: Grid<T>,
+	T: Coord
+{
+	type Item = G::Item;
+
+	fn contains_point(&self, point: Point<usize>) -> bool {
+		self.to_original(point).is_some_and(|p| self.grid.contains_point(p))
+	}
+
+	fn get_point(&self, point: Point<usize>) -> Option<&Self::Item> {
+		self.grid.get_point(self.to_original(point)?)
+	}
+
+	fn iter_grid(&self) -> Box<dyn Iterator<Item = (Point<usize>, &Self::Item)> + '_> {
+		let width = self.width();
+		Box::new((0..self.height() * width)
+			.map(move |i| Point::from((i / width, i % width)))
+			.filter_map(|p| Some((p, self.get_point(p)?))))
+	}
+
+	fn bounds(&self) -> Option<Rect<usize>> {
+		match self.height() == 0 || self.width() == 0 {
+			true => None,
+			false => Some(Rect::new(Point::default(), Point::from((self.height() - 1, self.width() - 1)))),
+		}
+	}
+}
+
+impl<G, T> GridMut<usize> for GridView<G, T>
+where
+	G: GridMut<T>,
+	T: Coord
+{
+	fn get_point_mut(&mut self, point: Point<usize>) -> Option<&mut Self::Item> {
+		let original = self.to_original(point)?;
+		self.grid.get_point_mut(original)
+	}
+
+	/// the cells are in the order of the inner grid's iter_rect_mut
+	fn iter_grid_mut(&mut self) -> Box<dyn Iterator<Item = (Point<usize>, &mut Self::Item)> + '_> {
+		let width = self.width();
+		let to_view: HashMap<Point<T>, Point<usize>> = (0..self.height() * width)
+			.map(|i| Point::from((i / width, i % width)))
+			.filter_map(|p| Some((self.to_original(p)?, p)))
+			.collect();
+		match self.rect {
+			Some(rect) => Box::new(self.grid.iter_rect_mut(rect).filter_map(move |(p, v)| Some((*to_view.get(&p)?, v)))),
+			None => Box::new(std::iter::empty()),
+		}
+	}
+}
+
+/// panics if the point is out of the view or the inner grid
+impl<G, T> ops::Index<Point<usize>> for GridView<G, T>
+where
+	G: Grid<T>,
+	T: Coord
+{
+	type Output = G::Item;
+	fn index(&self, index: Point<usize>) -> &Self::Output {
+		self.get_point(index).expect("Point out of the view")
+	}
+}
+
+impl<G, T> ops::IndexMut<Point<usize>> for GridView<G, T>
+where
+	G: GridMut<T>,
+	T: Coord
+{
+	fn index_mut(&mut self, index: Point<usize>) -> &mut Self::Output {
+		self.get_point_mut(index).expect("Point out of the view")
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::space_2D::ArrayGrid;
+
+	fn rows<G: Grid<usize, Item = u8>>(view: &GridView<G, usize>) -> Vec<String> {
+		(0..view.height())
+			.map(|y| (0..view.width()).map(|x| view[Point::from((y, x))] as char).collect())
+			.collect()
+	}
+
+	#[test]
+	fn test_transforms() {
+		let grid = ArrayGrid::from_rows(vec![b"abc".to_vec(), b"def".to_vec()]);
+		let expected = [
+			(Transform::Identity, vec!["abc", "def"]),
+			(Transform::Rotate90, vec!["da", "eb", "fc"]),
+			(Transform::Rotate180, vec!["fed", "cba"]),
+			(Transform::Rotate270, vec!["cf", "be", "ad"]),
+			(Transform::FlipX, vec!["cba", "fed"]),
+			(Transform::FlipY, vec!["def", "abc"]),
+			(Transform::Transpose, vec!["ad", "be", "cf"]),
+			(Transform::AntiTranspose, vec!["fc", "eb", "da"]),
+		];
+		for (transform, rows_expected) in expected {
+			assert_eq!(rows(&Grid::<usize>::view(&grid, transform)), rows_expected, "{:?}", transform);
+		}
+	}
+
+	#[test]
+	fn test_view_grid() {
+		let mut grid = ArrayGrid::from_rows(vec![b"abc".to_vec(), b"def".to_vec(), b"ghi".to_vec()]);
+		let window = Rect::new(Point::<usize>::from((1, 1)), Point::from((2, 2)));
+		assert_eq!(rows(&grid.view_rect(window, Transform::Rotate90)), vec!["he", "if"]);
+		let mut view = grid.view_rect_mut(window, Transform::Rotate90);
+		assert_eq!(rows(&view), vec!["he", "if"]);
+		assert_eq!(view.to_original(Point::from((0, 0))), Some(Point::from((2, 1))));
+		assert_eq!(view.to_original(Point::from((2, 0))), None);
+		assert!(!view.contains_point(Point::from((0, 2))));
+		assert_eq!(view.adjacents_4(Point::from((0, 0))).len(), 2);
+		assert_eq!(view.iter_grid().map(|(_, v)| *v).collect::<Vec<_>>(), b"heif".to_vec());
+
+		view[Point::from((0, 1))] = b'E';
+		view.iter_grid_mut().filter(|(p, _)| p.y == 1).for_each(|(_, v)| *v = v.to_ascii_uppercase());
+		assert_eq!(grid.as_slice(), b"abcdEFghI");
+	}
+}
@@ -0,0 +1,83 @@
+use super::PointN;
+use crate::space_2D::Coord;
+
+/// box between the min and max corners, both included
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Bounds<T: Coord, const N: usize> {
+	pub min: PointN<T, N>,
+	pub max: PointN<T, N>,
+}
+
+impl<T: Coord + Ord, const N: usize> Bounds<T, N> {
+	pub fn new(min: PointN<T, N>, max: PointN<T, N>) -> Self {
+		Bounds { min, max }
+	}
+
+	/// the smallest box containing all the points, or None if there are no points
+	pub fn from_points(points: impl IntoIterator<Item = PointN<T, N>>) -> Option<Self> {
+		let mut points = points.into_iter();
+		let first = points.next()?;
+		let mut bounds = Bounds::new(first, first);
+		points.for_each(|p| bounds.include(p));
+		Some(bounds)
+	}
+
+	/// grow the box, if needed, to contain the point
+	pub fn include(&mut self, point: PointN<T, N>) {
+		for axis in 0..N {
+			self.min[axis] = self.min[axis].min(point[axis]);
+			self.max[axis] = self.max[axis].max(point[axis]);
+		}
+	}
+
+	pub fn contains(&self, point: PointN<T, N>) -> bool {
+		(0..N).all(|axis| (self.min[axis]..=self.max[axis]).contains(&point[axis]))
+	}
+
+	/// the box with `by` more cells on each side, or None if it doesn't fit in T
+	pub fn expand(&self, by: isize) -> Option<Self> {
+		Some(Bounds::new(self.min.offset(PointN([-by; N]))?, self.max.offset(PointN([by; N]))?))
+	}
+
+	/// iterate over all the points of the box, with the last axis changing faster
+	pub fn iter(&self) -> impl Iterator<Item = PointN<T, N>> {
+		let bounds = *self;
+		let mut next = Some(self.min).filter(|_| (0..N).all(|axis| self.min[axis] <= self.max[axis]));
+
+		std::iter::from_fn(move || {
+			let point = next?;
+			next = (0..N).rev()
+				.find(|&axis| point[axis] < bounds.max[axis])
+				.map(|axis| {
+					let mut p = point;
+					p[axis] = p[axis].offset(1).unwrap();
+					p.0[axis + 1..].copy_from_slice(&bounds.min.0[axis + 1..]);
+					p
+				});
+			Some(point)
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::space_ND::Point3;
+
+	#[test]
+	fn test_bounds() {
+		let points: [Point3; 3] = [(1, 5, 2), (3, 0, 2), (2, 2, 4)].map(Point3::from);
+		let bounds = Bounds::from_points(points).unwrap();
+		assert_eq!(bounds, Bounds::new((1, 0, 2).into(), (3, 5, 4).into()));
+		assert!(bounds.contains((2, 5, 3).into()));
+		assert!(!bounds.contains((2, 6, 3).into()));
+		assert_eq!(bounds.iter().count(), 3 * 6 * 3);
+		assert_eq!(bounds.iter().nth(1), Some((1, 0, 3).into()));
+		assert!(bounds.iter().all(|p| bounds.contains(p)));
+
+		let expanded = bounds.expand(1).unwrap();
+		assert_eq!(expanded.min, (0, -1, 1).into());
+		assert_eq!(expanded.iter().count(), 5 * 8 * 5);
+		assert_eq!(Bounds::<usize, 2>::new(Default::default(), Default::default()).expand(1), None);
+	}
+}
@@ -0,0 +1,7 @@
+mod point;
+mod bounds;
+mod voxel_set;
+
+pub use self::point::{PointN, Point3};
+pub use self::bounds::Bounds;
+pub use self::voxel_set::VoxelSet;
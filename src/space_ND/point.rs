@@ -0,0 +1,253 @@
+use crate::space_2D::{Point, Coord};
+use std::ops::*;
+use std::fmt;
+
+/// coordenates of a N dimensional space, i.e. PointN::from([x, y, z])
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PointN<T: Coord, const N: usize>(pub [T; N]);
+
+/// point of a 3D space, with x, y, z coordenates
+pub type Point3<T = isize> = PointN<T, 3>;
+
+impl<T: Coord, const N: usize> PointN<T, N> {
+	pub fn to_array(&self) -> [T; N] {
+		self.0
+	}
+
+	/// the point moved by delta, or None if it doesn't fit in T
+	pub fn offset(&self, delta: PointN<isize, N>) -> Option<Self> {
+		let mut coords = self.0;
+		for (coord, delta) in coords.iter_mut().zip(delta.0) {
+			*coord = coord.offset(delta)?;
+		}
+		Some(PointN(coords))
+	}
+
+	/// the 2 * N points that share a face, i.e. 6 in 3D. The ones that don't fit
+	/// in T are skipped
+	pub fn adjacents(&self) -> impl Iterator<Item = Self> {
+		let point = *self;
+		(0..N).flat_map(|axis| [-1, 1].map(|delta| (axis, delta)))
+			.filter_map(move |(axis, delta)| {
+				let mut offset = PointN([0; N]);
+				offset.0[axis] = delta;
+				point.offset(offset)
+			})
+	}
+
+	/// the 3^N - 1 points that share a face, an edge or a corner, i.e. 26 in 3D.
+	/// The ones that don't fit in T are skipped
+	pub fn neighbours(&self) -> impl Iterator<Item = Self> {
+		let point = *self;
+		let center = (3usize.pow(N as u32) - 1) / 2;
+		(0..3usize.pow(N as u32))
+			.filter(move |i| *i != center)
+			.filter_map(move |mut i| {
+				let mut offset = PointN([0; N]);
+				for delta in offset.0.iter_mut() {
+					*delta = (i % 3) as isize - 1;
+					i /= 3;
+				}
+				point.offset(offset)
+			})
+	}
+}
+
+impl<T: Coord> PointN<T, 3> {
+	pub fn x(&self) -> T {
+		self.0[0]
+	}
+
+	pub fn y(&self) -> T {
+		self.0[1]
+	}
+
+	pub fn z(&self) -> T {
+		self.0[2]
+	}
+}
+
+impl<T: Coord, const N: usize> Default for PointN<T, N> {
+	fn default() -> Self {
+		PointN([T::default(); N])
+	}
+}
+
+impl<T: Coord, const N: usize> From<[T; N]> for PointN<T, N> {
+	fn from(coords: [T; N]) -> Self {
+		PointN(coords)
+	}
+}
+
+impl<T: Coord, const N: usize> From<PointN<T, N>> for [T; N] {
+	fn from(p: PointN<T, N>) -> Self {
+		p.0
+	}
+}
+
+/// Point3::from((x, y, z))
+impl<T: Coord> From<(T, T, T)> for PointN<T, 3> {
+	fn from(t: (T, T, T)) -> Self {
+		PointN([t.0, t.1, t.2])
+	}
+}
+
+impl<T: Coord> From<PointN<T, 3>> for (T, T, T) {
+	fn from(p: PointN<T, 3>) -> Self {
+		(p.0[0], p.0[1], p.0[2])
+	}
+}
+
+/// a 2D point is [y, x], so the coordenates are in the same order as in the tuples
+impl<T: Coord> From<Point<T>> for PointN<T, 2> {
+	fn from(p: Point<T>) -> Self {
+		PointN([p.y, p.x])
+	}
+}
+
+impl<T: Coord> From<PointN<T, 2>> for Point<T> {
+	fn from(p: PointN<T, 2>) -> Self {
+		Point { y: p.0[0], x: p.0[1] }
+	}
+}
+
+impl<T: Coord, const N: usize> Index<usize> for PointN<T, N> {
+	type Output = T;
+	fn index(&self, axis: usize) -> &Self::Output {
+		&self.0[axis]
+	}
+}
+
+impl<T: Coord, const N: usize> IndexMut<usize> for PointN<T, N> {
+	fn index_mut(&mut self, axis: usize) -> &mut Self::Output {
+		&mut self.0[axis]
+	}
+}
+
+impl<T, const N: usize> Add for PointN<T, N>
+where
+	T: Coord + Add<T, Output = T>
+{
+	type Output = PointN<T, N>;
+	fn add(self, rhs: Self) -> Self::Output {
+		PointN(std::array::from_fn(|i| self.0[i].add(rhs.0[i])))
+	}
+}
+
+impl<T, const N: usize> AddAssign for PointN<T, N>
+where
+	T: Coord + AddAssign<T>
+{
+	fn add_assign(&mut self, rhs: Self) {
+		for (coord, rhs) in self.0.iter_mut().zip(rhs.0) {
+			coord.add_assign(rhs);
+		}
+	}
+}
+
+impl<T, const N: usize> Sub for PointN<T, N>
+where
+	T: Coord + Sub<T, Output = T>
+{
+	type Output = PointN<T, N>;
+	fn sub(self, rhs: Self) -> Self::Output {
+		PointN(std::array::from_fn(|i| self.0[i].sub(rhs.0[i])))
+	}
+}
+
+impl<T, const N: usize> SubAssign for PointN<T, N>
+where
+	T: Coord + SubAssign<T>
+{
+	fn sub_assign(&mut self, rhs: Self) {
+		for (coord, rhs) in self.0.iter_mut().zip(rhs.0) {
+			coord.sub_assign(rhs);
+		}
+	}
+}
+
+impl<T, const N: usize> Mul<T> for PointN<T, N>
+where
+	T: Coord + Mul<T, Output = T>
+{
+	type Output = PointN<T, N>;
+	fn mul(self, rhs: T) -> Self::Output {
+		PointN(self.0.map(|coord| coord.mul(rhs)))
+	}
+}
+
+impl<T, const N: usize> MulAssign<T> for PointN<T, N>
+where
+	T: Coord + MulAssign<T>
+{
+	fn mul_assign(&mut self, rhs: T) {
+		for coord in self.0.iter_mut() {
+			coord.mul_assign(rhs);
+		}
+	}
+}
+
+impl<T, const N: usize> Div<T> for PointN<T, N>
+where
+	T: Coord + Div<T, Output = T>
+{
+	type Output = PointN<T, N>;
+	fn div(self, rhs: T) -> Self::Output {
+		PointN(self.0.map(|coord| coord.div(rhs)))
+	}
+}
+
+impl<T, const N: usize> DivAssign<T> for PointN<T, N>
+where
+	T: Coord + DivAssign<T>
+{
+	fn div_assign(&mut self, rhs: T) {
+		for coord in self.0.iter_mut() {
+			coord.div_assign(rhs);
+		}
+	}
+}
+
+impl<T, const N: usize> fmt::Display for PointN<T, N>
+where
+	T: Coord + fmt::Display
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "(")?;
+		for (i, coord) in self.0.iter().enumerate() {
+			match i {
+				0 => write!(f, "{}", coord)?,
+				_ => write!(f, ", {}", coord)?,
+			}
+		}
+		write!(f, ")")
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_ops() {
+		let p: Point3 = Point3::from((1, 2, 3));
+		assert_eq!(p + Point3::from((1, 1, -1)), Point3::from((2, 3, 2)));
+		assert_eq!(p - p, Point3::default());
+		assert_eq!(p * 2, Point3::from((2, 4, 6)));
+		assert_eq!((p * 4) / 2, p * 2);
+		assert_eq!((p.x(), p.y(), p.z()), (1, 2, 3));
+		assert_eq!(p.to_string(), "(1, 2, 3)");
+		assert_eq!(Point::from(PointN::from(Point::<usize>::from((4, 5)))), Point::from((4, 5)));
+	}
+
+	#[test]
+	fn test_neighbourhoods() {
+		let p = Point3::<isize>::from((0, 0, 0));
+		assert_eq!(p.adjacents().count(), 6);
+		assert!(p.adjacents().all(|a| a.0.iter().map(|c| c.abs()).sum::<isize>() == 1));
+		assert_eq!(p.neighbours().count(), 26);
+		assert!(!p.neighbours().any(|n| n == p));
+		assert_eq!(PointN::<usize, 3>::default().adjacents().count(), 3);
+		assert_eq!(PointN::<usize, 2>::default().neighbours().count(), 3);
+	}
+}
@@ -0,0 +1,103 @@
+use super::{PointN, Bounds};
+use crate::space_2D::Coord;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// sparse set of cells of a N dimensional space, i.e. the cubes of a 3D shape
+#[derive(Clone, Debug)]
+pub struct VoxelSet<T: Coord, const N: usize> {
+	voxels: HashSet<PointN<T, N>>,
+}
+
+impl<T: Coord + Ord + Hash, const N: usize> VoxelSet<T, N> {
+	pub fn new() -> Self {
+		VoxelSet { voxels: HashSet::new() }
+	}
+
+	/// returns false if the voxel was already set
+	pub fn insert(&mut self, point: PointN<T, N>) -> bool {
+		self.voxels.insert(point)
+	}
+
+	pub fn remove(&mut self, point: PointN<T, N>) -> bool {
+		self.voxels.remove(&point)
+	}
+
+	pub fn contains(&self, point: PointN<T, N>) -> bool {
+		self.voxels.contains(&point)
+	}
+
+	pub fn len(&self) -> usize {
+		self.voxels.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.voxels.is_empty()
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item = PointN<T, N>> + '_ {
+		self.voxels.iter().copied()
+	}
+
+	/// the smallest box containing all the voxels, or None if it's empty
+	pub fn bounds(&self) -> Option<Bounds<T, N>> {
+		Bounds::from_points(self.iter())
+	}
+
+	/// number of faces of the voxels that are not shared with another voxel,
+	/// including the ones of the inner holes. The faces at the limits of T, like
+	/// 0 for unsigned coordinates, are exposed too
+	pub fn surface_area(&self) -> usize {
+		self.iter()
+			.flat_map(|voxel| (0..N).flat_map(move |axis| [-1, 1].map(|delta| {
+				let mut offset = PointN([0; N]);
+				offset.0[axis] = delta;
+				voxel.offset(offset)
+			})))
+			.filter(|p| p.is_none_or(|p| !self.contains(p)))
+			.count()
+	}
+}
+
+impl<T: Coord + Ord + Hash, const N: usize> Default for VoxelSet<T, N> {
+	fn default() -> Self {
+		VoxelSet::new()
+	}
+}
+
+impl<T: Coord + Ord + Hash, const N: usize> FromIterator<PointN<T, N>> for VoxelSet<T, N> {
+	fn from_iter<I: IntoIterator<Item = PointN<T, N>>>(iter: I) -> Self {
+		VoxelSet { voxels: iter.into_iter().collect() }
+	}
+}
+
+impl<T: Coord + Ord + Hash, const N: usize> Extend<PointN<T, N>> for VoxelSet<T, N> {
+	fn extend<I: IntoIterator<Item = PointN<T, N>>>(&mut self, iter: I) {
+		self.voxels.extend(iter)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::space_ND::Point3;
+
+	#[test]
+	fn test_voxel_set() {
+		let mut voxels: VoxelSet<isize, 3> = [(1, 1, 1), (2, 1, 1)].map(Point3::from).into_iter().collect();
+		assert_eq!(voxels.surface_area(), 10);
+		assert!(!voxels.insert((1, 1, 1).into()));
+		assert!(voxels.remove((2, 1, 1).into()));
+		assert_eq!(voxels.surface_area(), 6);
+		assert_eq!(voxels.bounds().map(|b| b.max), Some((1, 1, 1).into()));
+		assert!(VoxelSet::<usize, 2>::new().bounds().is_none());
+	}
+
+	#[test]
+	fn test_surface_area_at_zero() {
+		let origin: VoxelSet<usize, 3> = [Point3::from((0, 0, 0))].into_iter().collect();
+		assert_eq!(origin.surface_area(), 6);
+		let edge: VoxelSet<u8, 2> = [[0, 255], [1, 255]].map(PointN).into_iter().collect();
+		assert_eq!(edge.surface_area(), 6);
+	}
+}